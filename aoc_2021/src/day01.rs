@@ -1,9 +1,11 @@
-use common::{input::Linewise, iter_ext::TryIterator};
+// aoc: title = "Sonar Sweep"
+
+use common::{input::{InputError, Linewise}, iter_ext::TryIterator};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    Parse(#[from] std::num::ParseIntError),
+    Parse(#[from] InputError<std::num::ParseIntError>),
 }
 
 pub fn task1(input: Linewise<u32>) -> Result<usize, Error> {
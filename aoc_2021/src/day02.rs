@@ -1,6 +1,8 @@
+// aoc: title = "Dive!", tags = [Parsing]
+
 use std::str::FromStr;
 
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -8,8 +10,12 @@ pub enum Error {
     ParseInt(#[from] std::num::ParseIntError),
     #[error("Invalid move input '{0}'")]
     ParseMove(String),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Debug)]
 pub enum Move {
     Forward(u32),
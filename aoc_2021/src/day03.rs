@@ -1,6 +1,8 @@
+// aoc: title = "Binary Diagnostic"
+
 use std::{str::FromStr, num::ParseIntError};
 
-use common::{input::Linewise, iter_ext::TryIterator};
+use common::{input::{InputError, Linewise}, iter_ext::TryIterator};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
@@ -25,11 +27,11 @@ impl FromStr for Number {
     }
 }
 
-pub fn task1(input: Linewise<Number>) -> Result<u32, ParseIntError> {
+pub fn task1(input: Linewise<Number>) -> Result<u32, InputError<ParseIntError>> {
     task1_core::<12>(input)
 }
 
-fn task1_core<const WIDTH: usize>(input: Linewise<Number>) -> Result<u32, ParseIntError> {
+fn task1_core<const WIDTH: usize>(input: Linewise<Number>) -> Result<u32, InputError<ParseIntError>> {
     let mut bit_count = [0_i16; WIDTH];
     for i in input {
         let i = i?;
@@ -55,11 +57,11 @@ fn task1_core<const WIDTH: usize>(input: Linewise<Number>) -> Result<u32, ParseI
     Ok(score)
 }
 
-pub fn task2(input: Linewise<Number>) -> Result<u32, ParseIntError> {
+pub fn task2(input: Linewise<Number>) -> Result<u32, InputError<ParseIntError>> {
     task2_core::<12>(input)
 }
 
-fn task2_core<const WIDTH: usize>(input: Linewise<Number>) -> Result<u32, ParseIntError> {
+fn task2_core<const WIDTH: usize>(input: Linewise<Number>) -> Result<u32, InputError<ParseIntError>> {
     let numbers: Vec<_> = input.try_collect2()?;
 
     let mut o2_numbers = numbers.clone();
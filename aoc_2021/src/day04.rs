@@ -1,6 +1,8 @@
+// aoc: title = "Giant Squid", tags = [Grid, Simulation]
+
 use std::str::FromStr;
 
-use common::{iter_ext::TryIterator, input::Multiline, debug::BinDebug};
+use common::{iter_ext::TryIterator, input::{InputError, Multiline}, debug::BinDebug};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -8,8 +10,12 @@ pub enum Error {
     ParseInt(#[from] std::num::ParseIntError),
     #[error(transparent)]
     Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 pub struct Board {
     numbers: [u8;25],
     marked_mask: u32,
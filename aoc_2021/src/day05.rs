@@ -1,11 +1,13 @@
+// aoc: title = "Hydrothermal Venture", tags = [Grid]
+
 use std::{str::FromStr, cmp::Ordering};
 
-use common::{input::Linewise, iter_ext::TryIterator};
+use common::{input::{InputError, Linewise}, iter_ext::TryIterator};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    Parse(#[from] pattern_parse::ParseError),
+    Parse(#[from] InputError<pattern_parse::ParseError>),
 }
 
 pattern_parse::parse_fn!(
@@ -81,6 +83,7 @@ impl Iterator for PointsIter {
     }
 }
 
+// aoc: slow
 pub fn task1(input: Linewise<Line>) -> Result<usize, Error> {
     let mut lines: Vec<_> = input.try_collect2()?;
     lines.retain(|l| l.is_horizontal() || l.is_vertical());
@@ -103,6 +106,7 @@ pub fn task1(input: Linewise<Line>) -> Result<usize, Error> {
     Ok(intersections.len())
 }
 
+// aoc: slow
 pub fn task2(input: Linewise<Line>) -> Result<usize, Error> {
     let lines: Vec<_> = input.try_collect2()?;
     let points = lines.iter()
@@ -1,9 +1,11 @@
-use common::input::CommaSeparated;
+// aoc: title = "Lanternfish", tags = [Simulation]
+
+use common::input::{CommaSeparated, InputError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseInt(#[from] std::num::ParseIntError)
+    ParseInt(#[from] InputError<std::num::ParseIntError>)
 }
 
 
@@ -57,6 +59,7 @@ pub fn task2(input: CommaSeparated<usize>) -> Result<u64, Error> {
 
 #[cfg(test)]
 mod tests {
+    use common::fuzz::{self, Case, Rng};
     use common::input::Input;
     use super::*;
 
@@ -76,4 +79,39 @@ mod tests {
         let val = result.unwrap();
         assert_eq!(val, 26984457539_u64);
     }
+
+    #[derive(Debug, Clone)]
+    struct Timers(Vec<u64>);
+
+    impl Case for Timers {
+        fn generate(rng: &mut Rng) -> Self {
+            Self((0..rng.range(1, 20)).map(|_| rng.range(0, 8)).collect())
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let shorter = fuzz::shrink_vec(&self.0, |t| fuzz::shrink_u64(*t, 0));
+            shorter.into_iter().filter(|t| !t.is_empty()).map(Self).collect()
+        }
+
+        fn render(&self) -> String {
+            self.0.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(",")
+        }
+    }
+
+    /// Simulates every fish on its own like the puzzle describes it.
+    fn simulate(input: &str) -> usize {
+        let mut fish = input.split(',').map(|t| t.parse::<u8>().unwrap()).collect::<Vec<_>>();
+        for _ in 0..80 {
+            let born = fish.iter().filter(|&&t| t == 0).count();
+            fish.iter_mut().for_each(|t| *t = if *t == 0 { 6 } else { *t - 1 });
+            fish.extend(std::iter::repeat(8).take(born));
+        }
+        fish.len()
+    }
+
+    #[test]
+    fn fuzz_task1() {
+        let task = crate::YEAR.day("day06").unwrap().task("task1").unwrap();
+        fuzz::check::<Timers>(50, fuzz::matches(task, simulate));
+    }
 }
@@ -1,9 +1,11 @@
-use common::{input::CommaSeparated, iter_ext::TryIterator};
+// aoc: title = "The Treachery of Whales"
+
+use common::{input::{CommaSeparated, InputError}, iter_ext::TryIterator};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseInt(#[from] std::num::ParseIntError),
+    ParseInt(#[from] InputError<std::num::ParseIntError>),
 }
 
 pub fn task1(input: CommaSeparated<u16>) -> Result<u32, Error> {
@@ -62,6 +64,7 @@ pub fn task2(input: CommaSeparated<u16>) -> Result<u32, Error> {
 
 #[cfg(test)]
 mod tests {
+    use common::fuzz::{self, Case, Rng};
     use common::input::Input;
     use super::*;
 
@@ -81,4 +84,41 @@ mod tests {
         let val = result.unwrap();
         assert_eq!(val, 168);
     }
+
+    #[derive(Debug, Clone)]
+    struct Positions(Vec<u64>);
+
+    impl Case for Positions {
+        fn generate(rng: &mut Rng) -> Self {
+            Self((0..rng.range(1, 30)).map(|_| rng.range(0, 200)).collect())
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let shorter = fuzz::shrink_vec(&self.0, |p| fuzz::shrink_u64(*p, 0));
+            shorter.into_iter().filter(|p| !p.is_empty()).map(Self).collect()
+        }
+
+        fn render(&self) -> String {
+            self.0.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",")
+        }
+    }
+
+    /// The cheapest total cost of moving all crabs to any position between the outermost ones.
+    fn cheapest(input: &str, cost: fn(u64) -> u64) -> u64 {
+        let positions = input.split(',').map(|p| p.parse::<u64>().unwrap()).collect::<Vec<_>>();
+        let (min, max) = (*positions.iter().min().unwrap(), *positions.iter().max().unwrap());
+        (min..=max).map(|x| positions.iter().map(|p| cost(p.abs_diff(x))).sum()).min().unwrap()
+    }
+
+    #[test]
+    fn fuzz_task1() {
+        let task = crate::YEAR.day("day07").unwrap().task("task1").unwrap();
+        fuzz::check::<Positions>(100, fuzz::matches(task, |input| cheapest(input, |d| d)));
+    }
+
+    #[test]
+    fn fuzz_task2() {
+        let task = crate::YEAR.day("day07").unwrap().task("task2").unwrap();
+        fuzz::check::<Positions>(100, fuzz::matches(task, |input| cheapest(input, |d| d * (d + 1) / 2)));
+    }
 }
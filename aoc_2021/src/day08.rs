@@ -1,6 +1,8 @@
+// aoc: title = "Seven Segment Search"
+
 use std::str::FromStr;
 
-use common::{iter_ext::TryIterator, input::Linewise};
+use common::{iter_ext::TryIterator, input::{InputError, Linewise}};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -8,8 +10,12 @@ pub enum Error {
     MissingDelimiter(&'static str),
     #[error("Input contained an unexpected character: '{0}'")]
     UnexpectedChar(char),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 bitflags::bitflags! {
     #[derive(Debug, Copy, Clone, PartialEq, Eq)]
     struct Segments: u8 {
@@ -1,3 +1,5 @@
+// aoc: title = "Smoke Basin", tags = [Grid]
+
 use common::{input::Linewise, some_or_continue};
 
 #[derive(Debug, thiserror::Error)]
@@ -1,3 +1,5 @@
+// aoc: title = "Syntax Scoring", tags = [Parsing]
+
 use common::input::{FromChar, Linewise, Charwise};
 
 #[derive(Debug, thiserror::Error)]
@@ -1,71 +1,61 @@
-use common::{input::digits::DigitMap, some_or_continue};
+// aoc: title = "Dumbo Octopus", tags = [Grid, Simulation]
+
+use common::geometry_2d::{Direction, Point};
+use common::grid::Grid;
+use common::some_or_continue;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
-pub fn task1(input: DigitMap<u8>) -> Result<u32, Error> {
-    let mut map = input.into_inner();
-
+pub fn task1(mut map: Grid<u8>) -> Result<u32, Error> {
     let mut flashes = 0;
     for _step in 0..100 {
-        for y in 0..map.len() {
-            for x in 0..map[y].len() {
-                increase_energy(&mut map, x, y, &mut flashes);
-            }
-        }
-        for row in map.iter_mut() {
-            for cell in row.iter_mut() {
-                if *cell > 9 { *cell = 0; }
-            }
+        for point in map.points() {
+            increase_energy(&mut map, point, &mut flashes);
         }
+        reset_flashed(&mut map);
     }
 
     Ok(flashes)
 }
 
-fn increase_energy(map: &mut Vec<Vec<u8>>, x: usize, y: usize, flashes: &mut u32) {
-    let cell = &mut map[y][x];
+fn increase_energy(map: &mut Grid<u8>, point: Point, flashes: &mut u32) {
+    let cell = &mut map[point];
     *cell += 1;
 
     if *cell != 10 { return; }
 
     *flashes += 1;
 
-    for y_offset in -1..=1 {
-        let new_y = some_or_continue!(y.checked_add_signed(y_offset));
-        let width = some_or_continue!(map.get(new_y)).len();
-        for x_offset in -1..=1 {
-            if x_offset == 0 && y_offset == 0 { continue; }
-
-            let new_x = some_or_continue!(x.checked_add_signed(x_offset));
-            if new_x < width {
-                increase_energy(map, new_x, new_y, flashes);
-            }
+    for dir in Direction::ALL {
+        let neighbour = some_or_continue!(point.offset(dir));
+        if map.contains(neighbour) {
+            increase_energy(map, neighbour, flashes);
         }
     }
 }
 
-pub fn task2(input: DigitMap<u8>) -> Result<u32, Error> {
-    let mut map = input.into_inner();
-    let size = (map.len() * map[0].len()) as u32;
+fn reset_flashed(map: &mut Grid<u8>) {
+    for point in map.points() {
+        let cell = &mut map[point];
+        if *cell > 9 { *cell = 0; }
+    }
+}
+
+pub fn task2(mut map: Grid<u8>) -> Result<u32, Error> {
+    let size = (map.width() * map.height()) as u32;
 
     for i in 0.. {
         let mut flashes = 0;
-        for y in 0..map.len() {
-            for x in 0..map[y].len() {
-                increase_energy(&mut map, x, y, &mut flashes);
-            }
+        for point in map.points() {
+            increase_energy(&mut map, point, &mut flashes);
         }
 
         if flashes == size { 
             return Ok(i + 1);
         }
 
-        for row in map.iter_mut() {
-            for cell in row.iter_mut() {
-                if *cell > 9 { *cell = 0; }
-            }
-        }
+        reset_flashed(&mut map);
     }
     unreachable!()
 }
@@ -1,11 +1,13 @@
+// aoc: title = "Passage Pathing", tags = [Pathfinding]
+
 use std::str::FromStr;
 
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    Parsing(#[from] pattern_parse::ParseError),
+    Parsing(#[from] InputError<pattern_parse::ParseError>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
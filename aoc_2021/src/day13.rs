@@ -1,13 +1,17 @@
+// aoc: title = "Transparent Origami", tags = [Grid]
+
 use std::str::FromStr;
 
-use common::{input::{LineSeparated, Linewise}, geometry_2d::{Point, PointParseError}};
+use common::{input::{InputError, LineSeparated, Linewise}, geometry_2d::{Point, PointParseError}, ocr::OcrError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    PointParse(#[from] PointParseError),
+    PointParse(#[from] InputError<PointParseError>),
     #[error(transparent)]
-    FoldParse(#[from] pattern_parse::ParseError),
+    FoldParse(#[from] InputError<pattern_parse::ParseError>),
+    #[error("{0}\n{1}")]
+    Ocr(OcrError, String),
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -47,7 +51,7 @@ impl FromStr for Fold {
     }
 }
 
-pub fn task1<'a>(input: LineSeparated<'a, Linewise<'static, Point>, Linewise<'a, Fold>>) -> Result<usize, Error> {
+pub fn task1<'a>(input: LineSeparated<'a, Linewise<'a, Point>, Linewise<'a, Fold>>) -> Result<usize, Error> {
     let (points, folds) = input.into_inner();
     let mut point_set = vec![];
     for point in points {
@@ -93,7 +97,7 @@ fn print_map(point_set: &Vec<Point>) -> String {
     text
 }
 
-pub fn task2<'a>(input: LineSeparated<'a, Linewise<'static, Point>, Linewise<'a, Fold>>) -> Result<String, Error> {
+pub fn task2<'a>(input: LineSeparated<'a, Linewise<'a, Point>, Linewise<'a, Fold>>) -> Result<String, Error> {
     let (points, folds) = input.into_inner();
     let mut point_set = vec![];
     for point in points {
@@ -107,8 +111,7 @@ pub fn task2<'a>(input: LineSeparated<'a, Linewise<'static, Point>, Linewise<'a,
         }
     }
 
-    let str = print_map(&point_set);
-    Ok(str)
+    common::ocr::decode_points(&point_set).map_err(|e| Error::Ocr(e, print_map(&point_set)))
 }
 
 #[cfg(test)]
@@ -145,4 +148,11 @@ fold along x=5".as_bytes();
         let val = result.unwrap();
         assert_eq!(val, 17);
     }
+
+    #[test]
+    fn test_task2() {
+        let input = include_bytes!("../inputs/day13.txt");
+        let result = task2(Input::parse_bytes(input).unwrap());
+        assert_eq!(result.unwrap(), "ECFHLHZF");
+    }
 }
@@ -1,6 +1,8 @@
+// aoc: title = "Extended Polymerization", tags = [Simulation]
+
 use ahash::{HashMap, HashMapExt};
 use common::{
-    input::{LineSeparated, Linewise},
+    input::{InputError, LineSeparated, Linewise},
     iter_ext::TryIterator,
 };
 use std::str::FromStr;
@@ -8,7 +10,7 @@ use std::str::FromStr;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Parse error: {0}")]
-    Parse(#[from] pattern_parse::ParseError),
+    Parse(#[from] InputError<pattern_parse::ParseError>),
 }
 
 pub struct Rule {
@@ -1,19 +1,20 @@
+// aoc: title = "Chiton", tags = [Grid, Pathfinding]
+
 use common::{
     geometry_2d::{Direction, Point},
-    input::digits::DigitMap,
+    grid::Grid,
     pathfinding::{self as pf, Algorithm},
-    some_or_continue,
 };
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
 #[derive(Debug)]
-struct Map(Vec<Vec<u8>>);
+struct Map(Grid<u8>);
 
 impl Map {
     fn value_of(&self, p: Point) -> u32 {
-        self.0[p.y as usize][p.x as usize] as u32
+        self.0[p] as u32
     }
 }
 
@@ -22,14 +23,7 @@ impl<'a> pf::World<'a> for Map {
     type Neighbors = Vec<Point>;
 
     fn get_neighbors(&'a self, origin: &Self::Index) -> Self::Neighbors {
-        let mut neighbors = vec![];
-        for dir in Direction::CARDINALS {
-            let p = some_or_continue!(Point::offset(*origin, dir));
-            let row = some_or_continue!(self.0.get(p.y as usize));
-            let _cell = some_or_continue!(row.get(p.x as usize));
-            neighbors.push(p);
-        }
-        neighbors
+        self.0.neighbours(*origin, &Direction::CARDINALS).map(|(p, _)| p).collect()
     }
 }
 
@@ -43,38 +37,27 @@ impl<'a> pf::Agent<'a, Map> for Agent {
     }
 }
 
-pub fn task1(input: DigitMap<u8>) -> Result<u32, Error> {
-    let map = Map(input.into_inner());
-
+/// The lowest total risk of any path from the top left to the bottom right corner.
+fn lowest_risk(map: &Map) -> u32 {
     let start = Point { x: 0, y: 0 };
-    let height = map.0.len();
-    let width = map.0[0].len();
     let target = Point {
-        x: (width - 1) as u32,
-        y: (height - 1) as u32,
+        x: (map.0.width() - 1) as u32,
+        y: (map.0.height() - 1) as u32,
     };
 
-    let path = pf::djikstra::Algorithm.get_path(&map, &Agent, start, target)
+    let path = pf::djikstra::Algorithm.get_path(map, &Agent, start, target)
         .expect("Failed to find path");
 
-    let result = path.positions()[1..].iter().map(|p| map.value_of(*p)).sum::<u32>();
-    Ok(result)
+    path.positions()[1..].iter().map(|p| map.value_of(*p)).sum::<u32>()
 }
 
-pub fn copy_plus_one(src: &[u8], dest: &mut [u8]) {
-    debug_assert_eq!(src.len(), dest.len());
-
-    for i in 0..src.len() {
-        let mut v = src[i];
-        v += 1;
-        if v == 10 { v = 1 };
-        dest[i] = v;
-    }
+pub fn task1(input: Grid<u8>) -> Result<u32, Error> {
+    Ok(lowest_risk(&Map(input)))
 }
 
 #[allow(dead_code)]
-fn print_map(map: &[Vec<u8>]) {
-    for row in map {
+fn print_map(map: &Grid<u8>) {
+    for row in map.rows() {
         for cell in row {
             print!("{}", cell);
         }
@@ -82,57 +65,19 @@ fn print_map(map: &[Vec<u8>]) {
     }
 }
 
-pub fn task2(input: DigitMap<u8>) -> Result<u32, Error> {
-    let init = input.into_inner();
-    let init_height = init.len();
-    let init_width = init[0].len();
-
-    let mut vecs = Vec::with_capacity(init.len() * 5);
-    for y in 0..init.len() {
-        // section 1
-        let mut vec = vec![0; init_width * 5];
-        vec[..init_width].copy_from_slice(&init[y]);
-        // section 2
-        let (old, new) = vec.split_at_mut(init_width);
-        copy_plus_one(old, &mut new[..init_width]);
-        // section 3
-        let (old, new) = new.split_at_mut(init_width);
-        copy_plus_one(old, &mut new[..init_width]);
-        // section 4
-        let (old, new) = new.split_at_mut(init_width);
-        copy_plus_one(old, &mut new[..init_width]);
-        // section 5
-        let (old, new) = new.split_at_mut(init_width);
-        copy_plus_one(old, &mut new[..init_width]);
-        // done
-        vecs.push(vec);
-    }
+pub fn task2(init: Grid<u8>) -> Result<u32, Error> {
+    let (init_width, init_height) = (init.width(), init.height());
 
-    for block in 0..4 {
-        for y in 0..init_height {
-            let src = &vecs[y + init_height * block];
-            let mut dest = vec![0; init_width * 5];
-            copy_plus_one(&src, &mut dest);
-            vecs.push(dest);
-        }
+    // the map is repeated five times in each direction, each copy being one riskier
+    // than the one to its left or above it, wrapping around from 9 to 1
+    let mut map = Grid::new(init_width * 5, init_height * 5, 0);
+    for point in map.points() {
+        let copy = point.x as usize / init_width + point.y as usize / init_height;
+        let source = Point::new(point.x % init_width as u32, point.y % init_height as u32);
+        map[point] = ((init[source] as usize + copy - 1) % 9 + 1) as u8;
     }
-    
-    let map = Map(vecs);
-
-    let start = Point { x: 0, y: 0 };
-    let height = map.0.len();
-    let width = map.0[0].len();
-    let target = Point {
-        x: (width - 1) as u32,
-        y: (height - 1) as u32,
-    };
-
-    let alg = pf::djikstra::Algorithm;
-    let path = alg.get_path(&map, &Agent, start, target)
-        .expect("Failed to find path");
 
-    let result = path.positions()[1..].iter().map(|p| map.value_of(*p)).sum::<u32>();
-    Ok(result)
+    Ok(lowest_risk(&Map(map)))
 }
 
 #[cfg(test)]
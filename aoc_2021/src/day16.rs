@@ -1,3 +1,5 @@
+// aoc: title = "Packet Decoder", tags = [Parsing]
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
@@ -1,3 +1,5 @@
+// aoc: title = "Trick Shot", tags = [Simulation]
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Parse error: {0}")]
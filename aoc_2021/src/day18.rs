@@ -1,15 +1,21 @@
+// aoc: title = "Snailfish", tags = [Parsing]
+
 use std::{fmt::Display, str::FromStr};
 use std::fmt::Write;
 
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 use common::iter_ext::TryIterator;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
     Parse(#[from] std::num::ParseIntError),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Debug, Clone)]
 pub struct SnailfishTree {
     root: SnailfishNode,
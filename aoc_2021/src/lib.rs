@@ -1,20 +1 @@
-common::decl_year!{
-    day01 {task1;task2;}
-    day02 {task1;task2;}
-    day03 {task1;task2;}
-    day04 {task1;task2;}
-    day05 {task1;task2;}
-    day06 {task1;task2;}
-    day07 {task1;task2;}
-    day08 {task1;task2;}
-    day09 {task1;task2;}
-    day10 {task1;task2;}
-    day11 {task1;task2;}
-    day12 {task1;task2;}
-    day13 {task1;task2;}
-    day14 {task1;task2;}
-    day15 {task1;task2;}
-    day16 {task1;task2;}
-    day17 {task1;task2;}
-    day18 {task1;task2;}
-}
\ No newline at end of file
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
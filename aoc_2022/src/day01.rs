@@ -1,7 +1,9 @@
+// aoc: title = "Calorie Counting"
+
 use std::num::ParseIntError;
-use common::input::Grouped;
+use common::input::{Grouped, InputError};
 
-pub fn task1(mut input: Grouped<u64>) -> Result<u64, ParseIntError> {
+pub fn task1(mut input: Grouped<u64>) -> Result<u64, InputError<ParseIntError>> {
     let mut max = 0;
     while let Some(group) = input.next() {
         let group= group?;
@@ -9,7 +11,7 @@ pub fn task1(mut input: Grouped<u64>) -> Result<u64, ParseIntError> {
     }
     Ok(max)
 }
-pub fn task2(mut input: Grouped<u64>) -> Result<u64, ParseIntError> {
+pub fn task2(mut input: Grouped<u64>) -> Result<u64, InputError<ParseIntError>> {
     let mut values = Vec::new();
     while let Some(group) = input.next() {
         values.push(group?.into_iter().sum::<u64>());
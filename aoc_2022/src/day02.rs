@@ -1,5 +1,7 @@
+// aoc: title = "Rock Paper Scissors"
+
 use std::str::FromStr;
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 trait Score {
     fn score(&self) -> u64;
@@ -119,7 +121,7 @@ fn get_move(opponent: Move, outcome: Outcome) -> Move {
         .unwrap()
 }
 
-pub fn task1(input: Linewise<MoveMove>) -> Result<u64, String> {
+pub fn task1(input: Linewise<MoveMove>) -> Result<u64, InputError<String>> {
     let mut score = 0_u64;
     common::for_input!(input, |t| {
         score += t.0.score() + t.0.vs(t.1).score();
@@ -128,7 +130,7 @@ pub fn task1(input: Linewise<MoveMove>) -> Result<u64, String> {
     Ok(score)
 }
 
-pub fn task2(input: Linewise<MoveOutcome>) -> Result<u64, String> {
+pub fn task2(input: Linewise<MoveOutcome>) -> Result<u64, InputError<String>> {
     let mut score = 0;
     common::for_input!(input, |t| {
         score += get_move(t.0, t.1).score() + t.1.score();
@@ -1,3 +1,5 @@
+// aoc: title = "Rucksack Reorganization"
+
 use std::collections::HashSet;
 use std::convert::Infallible;
 
@@ -1,7 +1,9 @@
+// aoc: title = "Camp Cleanup"
+
 use std::num::ParseIntError;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -9,8 +11,12 @@ pub enum Error {
     ParseIntError(#[from] ParseIntError),
     #[error("Line was missing split char '{0}'")]
     MissingSplit(char),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 pub struct RangePair(RangeInclusive<usize>, RangeInclusive<usize>);
 
 fn parse_range(s: &str) -> Result<RangeInclusive<usize>, Error> {
@@ -1,61 +1,80 @@
+// aoc: title = "Supply Stacks", tags = [Parsing, Simulation]
+
+use common::input::{Columns, Fixed, FooterRow, InputError, Linewise, SectionError, Sections};
 use std::{cell::RefCell, str::FromStr};
-use std::convert::Infallible;
 
-struct Move {
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Sections(#[from] SectionError),
+    #[error(transparent)]
+    Crate(#[from] InputError<InvalidCrate>),
+    #[error(transparent)]
+    Move(#[from] InputError<pattern_parse::ParseError>),
+}
+
+pub struct Move {
     source: usize,
     destination: usize,
     depth: usize,
 }
 
-fn parse<'a>(input: &'a str) -> (Vec<RefCell<Vec<char>>>, impl 'a + Iterator<Item=Move>) {
-    let cols = parse_columns(input);
-    let moves = parse_moves(input);
-    (cols, moves)
+pattern_parse::parse_fn!(parse_move, "move {usize} from {usize} to {usize}");
+
+impl FromStr for Move {
+    type Err = pattern_parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (depth, source, destination) = parse_move(s)?;
+        Ok(Move {
+            source,
+            destination,
+            depth,
+        })
+    }
 }
 
-fn parse_columns(input: &str) -> Vec<RefCell<Vec<char>>> {
-    let lines = input.lines().take_while(|s| s.trim().len() > 0);
-    let last = lines.clone().last().unwrap();
-    let width = last.len() / 4 + 1;
-
-    let stacks = vec![RefCell::new(Vec::<char>::new()); width];
-    for line in lines {
-        for (col, c) in line.chars().skip(1).step_by(4).enumerate() {
-            if c.is_ascii_alphabetic() {
-                // always insert at the bottom of the stack (reading top down)
-                stacks[col].borrow_mut().insert(0, c);
-            }
+/// A crate drawn as `[Z]`.
+struct Crate(char);
+
+#[derive(Debug, thiserror::Error)]
+#[error("Invalid crate '{0}'")]
+pub struct InvalidCrate(String);
+
+impl FromStr for Crate {
+    type Err = InvalidCrate;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some('['), Some(c), Some(']'), None) => Ok(Crate(c)),
+            _ => Err(InvalidCrate(s.to_owned())),
         }
     }
-
-    stacks
 }
 
-fn parse_moves<'a>(input: &'a str) -> impl 'a + Iterator<Item=Move> {
-    input
-        .lines()
-        .skip_while(|s| s.trim().len() > 0)
-        .skip(1) // ignore the blank line separator
-        .map(|s| {
-            let mut split = s.split_whitespace();
-            split.next().unwrap(); // move
-            let depth = usize::from_str(split.next().unwrap()).unwrap();
-            split.next().unwrap(); // from
-            let source = usize::from_str(split.next().unwrap()).unwrap();
-            split.next().unwrap(); // to
-            let destination = usize::from_str(split.next().unwrap()).unwrap();
-
-            Move {
-                source,
-                destination,
-                depth,
-            }
+/// The stacks of crates with their numbers below them.
+type Drawing = Columns<Fixed<4>, FooterRow>;
+
+fn parse_stacks(drawing: &Drawing) -> Result<Vec<RefCell<Vec<char>>>, Error> {
+    (0..drawing.width())
+        .map(|x| {
+            // read bottom up so the top crate ends up last
+            let stack = drawing
+                .parse_column::<Crate>(x)
+                .rev()
+                .map(|c| c.map(|c| c.0))
+                .collect::<Result<_, _>>()?;
+            Ok(RefCell::new(stack))
         })
+        .collect()
 }
 
-pub fn task1(input: String) -> Result<String, Infallible> {
-    let (columns, moves) = parse(&input);
+pub fn task1(input: Sections<(Drawing, Linewise<Move>)>) -> Result<String, Error> {
+    let (drawing, moves) = input.into_inner();
+    let columns = parse_stacks(&drawing)?;
     for m in moves {
+        let m = m?;
         let mut source = columns[m.source - 1].borrow_mut();
         let first = source.len() - m.depth;
         let drain = source.drain(first..);
@@ -70,9 +89,11 @@ pub fn task1(input: String) -> Result<String, Infallible> {
     Ok(chars)
 }
 
-pub fn task2(input: String) -> Result<String, Infallible> {
-    let (columns, moves) = parse(&input);
+pub fn task2(input: Sections<(Drawing, Linewise<Move>)>) -> Result<String, Error> {
+    let (drawing, moves) = input.into_inner();
+    let columns = parse_stacks(&drawing)?;
     for m in moves {
+        let m = m?;
         let mut source = columns[m.source - 1].borrow_mut();
         let first = source.len() - m.depth;
         let drain = source.drain(first..);
@@ -86,3 +107,34 @@ pub fn task2(input: String) -> Result<String, Infallible> {
         .collect::<String>();
     Ok(chars)
 }
+
+#[cfg(test)]
+mod tests {
+    use common::input::Input;
+    use super::*;
+
+    const INPUT: &[u8] = br"    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn test_task1() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task1(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, "CMZ");
+    }
+    #[test]
+    fn test_task2() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task2(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, "MCD");
+    }
+}
@@ -1,3 +1,5 @@
+// aoc: title = "Tuning Trouble"
+
 #[derive(Debug, thiserror::Error)]
 #[error("No distinct section was found")]
 pub struct NoDistinct;
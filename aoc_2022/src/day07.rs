@@ -1,3 +1,5 @@
+// aoc: title = "No Space Left On Device", tags = [Parsing]
+
 use std::convert::Infallible;
 use std::io::BufRead;
 use std::num::ParseIntError;
@@ -1,3 +1,5 @@
+// aoc: title = "Rope Bridge", tags = [Simulation]
+
 use std::{
     cell::RefCell,
     collections::HashSet,
@@ -5,7 +7,7 @@ use std::{
     str::FromStr,
 };
 use std::num::ParseIntError;
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -15,8 +17,12 @@ pub enum Error {
     UnknownMove(String),
     #[error(transparent)]
     IoError( #[from] std::io::Error),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Move {
     Up,
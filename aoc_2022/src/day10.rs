@@ -1,6 +1,8 @@
+// aoc: title = "Cathode-Ray Tube", tags = [Simulation]
+
 use std::{str::FromStr, iter::Iterator, num::ParseIntError};
 
-use common::input::Linewise;
+use common::{input::{InputError, Linewise}, ocr::OcrError};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -8,8 +10,14 @@ pub enum Error {
     ParseIntError(#[from] ParseIntError),
     #[error("Invalid change format '{0}'")]
     InvalidChange(String),
+    #[error("{0}\n{1}")]
+    Ocr(OcrError, String),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Debug)]
 pub enum Change {
     AddX(isize),
@@ -86,6 +94,19 @@ pub fn task2(input: Linewise<Change>) -> Result<String, Error> {
         };
         buffer.push(c)
     }
-    
-    Ok(buffer)
-}
\ No newline at end of file
+
+    common::ocr::decode_str(&buffer).map_err(|e| Error::Ocr(e, buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::input::Input;
+    use super::*;
+
+    #[test]
+    fn test_task2() {
+        let input = include_bytes!("../inputs/day10.txt");
+        let result = task2(Input::parse_bytes(input).unwrap());
+        assert_eq!(result.unwrap(), "ZFBFHGUP");
+    }
+}
@@ -1,6 +1,9 @@
+// aoc: title = "Monkey in the Middle", tags = [Parsing, Simulation, NumberTheory]
+
 use std::{cell::RefCell, str::FromStr, fmt::Debug, num::ParseIntError};
 
-use common::{input::Multiline, iter_ext::TryIterator};
+use common::{input::{InputError, Multiline}, iter_ext::TryIterator};
+use pattern_parse::PatternParse;
 
 #[derive(Debug)]
 pub enum OpValue {
@@ -67,75 +70,37 @@ impl FromStr for Operation {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, PatternParse)]
+#[pattern("Monkey {_}:
+  Starting items: {items}
+  Operation: new = {op}
+  Test: divisible by {test}
+    If true: throw to monkey {true_target}
+    If false: throw to monkey {false_target}")]
 pub struct Monkey {
+    #[pattern(with = parse_items)]
     items: RefCell<Vec<u64>>,
     op: Operation,
     test: u64,
     true_target: usize,
     false_target: usize,
+    #[pattern(default)]
     inspected_items: usize,
 }
 
-impl FromStr for Monkey {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.trim().lines();
-        // Monkey 1:
-        lines.next().ok_or(Error::LineGrouping(0))?;
-        // Starting items: 54, 65, 75, 74
-        let items = parse_items(lines.next().ok_or(Error::LineGrouping(1))?)?;
-        // Operation: new = old + 3
-        let op = Operation::from_str(lines.next().ok_or(Error::LineGrouping(2))?)?;
-        // Test: divisible by 17
-        let test : u64 = get_trailing_num(lines.next().ok_or(Error::LineGrouping(3))?)?;
-        //   If true: throw to monkey 0
-        let true_target = get_trailing_num(lines.next().ok_or(Error::LineGrouping(4))?)?;
-        //   If false: throw to monkey 1
-        let false_target = get_trailing_num(lines.next().ok_or(Error::LineGrouping(6))?)?;
-
-        Ok(Monkey {
-            items: RefCell::new(items),
-            op,
-            test: test.into(),
-            true_target,
-            false_target,
-            inspected_items: 0,
-        })
-    }
-}
-
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error(transparent)]
-    ParseInt(#[from]ParseIntError),
-    #[error("Errornious line grouping")]
-    LineGrouping(usize),
     #[error("Unknown value '{0}'")]
     InvalidValue(String),
     #[error("Unknown operator '{0}'")]
     InvalidOperation(String),
+    #[error(transparent)]
+    Parse(#[from] InputError<pattern_parse::ParseError>),
 }
 
-fn parse_items(line: &str) -> Result<Vec<u64>, Error> {
-    let mut iter = (line.trim()).split(' ');
-    // "Starting "
-    iter.next().unwrap();
-    // "items: "
-    iter.next().unwrap();
-
-    let mut vec = Vec::new();
-    while let Some(mut part) = iter.next() {
-        part = part.trim_end_matches(',');
-        vec.push((part).trim().parse::<u64>()?)
-    }
-    Ok(vec)
-}
-
-fn get_trailing_num<E: Debug, T: FromStr<Err = E>>(line: &str) -> Result<T,E> {
-    let mut iter = line.trim().split(' ').rev();
-    T::from_str(iter.next().unwrap())
+fn parse_items(s: &str) -> Result<RefCell<Vec<u64>>, ParseIntError> {
+    let items = s.split(", ").map(u64::from_str).collect::<Result<_, _>>()?;
+    Ok(RefCell::new(items))
 }
 
 fn run_turn(active_id: usize, monkeys: &mut Vec<Monkey>, common_factor: u64) {
@@ -188,3 +153,62 @@ pub fn task2(monkeys: Multiline<Monkey, 6, true>) -> Result<usize, Error> {
     inspecitons.sort_unstable_by(|a,b| a.cmp(b).reverse());
     Ok(inspecitons[0] * inspecitons[1])
 }
+
+#[cfg(test)]
+mod tests {
+    use common::input::Input;
+    use super::*;
+
+    const INPUT: &[u8] = b"\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn parses_monkeys() {
+        let monkeys = Multiline::<Monkey, 6, true>::parse(INPUT).unwrap().try_collect2::<Vec<_>>().unwrap();
+        assert_eq!(monkeys.len(), 4);
+        let monkey = &monkeys[0];
+        assert_eq!(*monkey.items.borrow(), [79, 98]);
+        assert!(matches!(monkey.op, Operation::Multiply(OpValue::Number(19))));
+        assert_eq!((monkey.test, monkey.true_target, monkey.false_target), (23, 2, 3));
+        assert!(matches!(monkeys[2].op, Operation::Multiply(OpValue::Old)));
+    }
+    #[test]
+    fn test_task2() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task2(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, 2713310158);
+    }
+    #[test]
+    fn crlf_line_endings() {
+        let input = String::from_utf8_lossy(INPUT).replace('\n', "\r\n");
+        let result = task2(Input::parse(input.as_bytes()).unwrap());
+        assert_eq!(result.unwrap(), 2713310158);
+    }
+}
@@ -1,46 +1,58 @@
+// aoc: title = "Hill Climbing Algorithm", tags = [Grid, Pathfinding]
+
 use std::io::BufRead;
 
-use common::{pathfinding as pf, input::Input};
+use common::geometry_2d::{Direction, Point};
+use common::grid::{Grid, GridError};
+use common::{pathfinding as pf, input::{Input, UnexpectedChar}};
 
-pub struct Vec2d<T>(Vec<Vec<T>>);
+pub struct Map {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
 
-type Map = Vec2d<char>;
+#[derive(Debug, thiserror::Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Grid(#[from] GridError<UnexpectedChar>),
+    #[error("missing marker '{0}'")]
+    MissingMarker(char),
+}
 
 impl Input<'_> for Map {
-    type Error = std::io::Error;
-    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
-        let mut lines = Vec::new();
-        let mut buf = String::new();
-        while read.read_line(&mut buf)? > 0 {
-            let line = buf.trim().chars().collect();
-            lines.push(line);
-            buf.clear();
-        }
-        Ok(Vec2d(lines))
+    type Error = ParseError;
+    fn parse<R: BufRead>(read: R) -> Result<Self, Self::Error> {
+        let (mut start, mut end) = (None, None);
+        let heights = Grid::parse_with(read, |point, c| {
+            match c {
+                'S' => {
+                    start = Some(point);
+                    Ok(b'a')
+                }
+                'E' => {
+                    end = Some(point);
+                    Ok(b'z')
+                }
+                'a'..='z' => Ok(c as u8),
+                c => Err(UnexpectedChar(c)),
+            }
+        })?;
+        Ok(Map {
+            heights,
+            start: start.ok_or(ParseError::MissingMarker('S'))?,
+            end: end.ok_or(ParseError::MissingMarker('E'))?,
+        })
     }
 }
 
 impl pf::World<'_> for Map {
-    type Index = (usize, usize);
+    type Index = Point;
     type Neighbors = std::vec::IntoIter<Self::Index>;
 
     fn get_neighbors(&self, origin: &Self::Index) -> Self::Neighbors {
-        let mut vec = Vec::new();
-
-        if origin.0 > 0 {
-            vec.push((origin.0 - 1, origin.1));
-        }
-        if origin.1 > 0 {
-            vec.push((origin.0, origin.1 - 1));
-        }
-        if origin.0 < self.0[0].len() - 1 {
-            vec.push((origin.0 + 1, origin.1));
-        }
-        if origin.1 < self.0.len() - 1 {
-            vec.push((origin.0, origin.1 + 1));
-        }
-
-        vec.into_iter()
+        let neighbors = self.heights.neighbours(*origin, &Direction::CARDINALS).map(|(p, _)| p);
+        neighbors.collect::<Vec<_>>().into_iter()
     }
 }
 
@@ -54,59 +66,22 @@ impl pf::Agent<'_, Map> for Agent {
         start: &<Map as pf::World>::Index,
         destination: &<Map as pf::World>::Index,
     ) -> Option<Self::Cost> {
-        let mut start_char = world.0[start.1][start.0];
-        if start_char == 'S' {
-            start_char = 'a'
-        }
-        let mut dest_char = world.0[destination.1][destination.0];
-        if dest_char == 'E' {
-            dest_char = 'z'
-        }
+        let start_num = world.heights[*start];
+        let destination_num = world.heights[*destination];
 
-        let start_num = start_char as u8;
-        let destination_num = dest_char as u8;
-
-        let result = if start_num + 1 >= destination_num {
+        if start_num + 1 >= destination_num {
             Some(1)
         } else {
             None
-        };
-        // dbg!(start_char, dest_char, result);
-        result
-    }
-}
-
-fn find(c: char, map: &Map) -> (usize, usize) {
-    for y in 0..map.0.len() {
-        for x in 0..map.0[0].len() {
-            if map.0[y][x] == c {
-                return (x, y);
-            }
-        }
-    }
-    panic!("char not found '{}'", c)
-}
-
-fn find_all(c: char, map: &Map) -> Vec<(usize, usize)> {
-    let mut vec = Vec::new();
-    for y in 0..map.0.len() {
-        for x in 0..map.0[y].len() {
-            if map.0[y][x] == c {
-                vec.push((x, y));
-            }
         }
     }
-    vec
 }
 
 pub fn task1(map: Map) -> Result<usize, pf::djikstra::Error>{
     let agent = Agent;
     let alg = pf::djikstra::Algorithm;
 
-    let start = find('S', &map);
-    let end = find('E', &map);
-
-    let path = pf::Algorithm::get_path(&alg, &map, &agent, start, end)?;
+    let path = pf::Algorithm::get_path(&alg, &map, &agent, map.start, map.end)?;
     Ok(path.len())
 }
 
@@ -114,13 +89,11 @@ pub fn task2(map: Map) -> Result<usize, pf::djikstra::Error>{
     let agent = Agent;
     let alg = pf::djikstra::Algorithm;
 
-    let start = find('S', &map);
-    let end = find('E', &map);
-
-    let mut path = pf::Algorithm::get_path(&alg, &map, &agent, start, end)?;
+    let mut path = pf::Algorithm::get_path(&alg, &map, &agent, map.start, map.end)?;
 
-    for start in find_all('a', &map) {
-        pf::Algorithm::get_path(&alg, &map, &agent, start, end).ok().map(
+    let lowest = map.heights.iter().filter(|(_, &h)| h == b'a').map(|(p, _)| p);
+    for start in lowest {
+        pf::Algorithm::get_path(&alg, &map, &agent, start, map.end).ok().map(
             |new_path| {
                 if new_path.len() < path.len() {
                     path = new_path;
@@ -135,25 +108,57 @@ pub fn task2(map: Map) -> Result<usize, pf::djikstra::Error>{
 fn print_path(path: pf::Path<'_, Map>) {
     let world = path.world();
 
-    let mut chars = world.0.clone();
+    let mut chars = world.heights.map(|&h| h as char);
 
-    for indices in path.positions().windows(2) {
-        let (x, y) = indices[0];
-        let (next_x, next_y) = indices[1];
-        let c = if next_x > x {
+    for points in path.positions().windows(2) {
+        let (from, to) = (points[0], points[1]);
+        let c = if to.x > from.x {
             '>'
-        } else if x > next_x {
+        } else if from.x > to.x {
             '<'
-        } else if next_y < y {
+        } else if to.y < from.y {
             '^'
         } else {
             'v'
         };
-        chars[y][x] = c;
+        chars[from] = c;
     }
 
-    for line in chars {
+    for line in chars.rows() {
         let s =  String::from_iter(line);
         println!("{}", s);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &[u8] = b"\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn test_task1() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task1(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, 32);
+    }
+    #[test]
+    fn test_task2() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task2(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, 30);
+    }
+
+    #[test]
+    fn rejects_invalid_heights() {
+        let err = Map::parse("Sab\naéE".as_bytes()).err().unwrap();
+        assert!(matches!(err, ParseError::Grid(GridError::Cell(e)) if (e.line, e.column) == (2, 2)));
+    }
+}
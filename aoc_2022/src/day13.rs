@@ -1,17 +1,23 @@
+// aoc: title = "Distress Signal", tags = [Parsing]
+
 use std::{
     cmp::Ordering,
     fmt::Debug,
     str::FromStr, num::ParseIntError,
 };
 
-use common::{input::Chunked, iter_ext::*};
+use common::{input::{Chunked, InputError}, iter_ext::*};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
     ParseIntError(#[from] ParseIntError),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Item {
     List(Vec<Item>),
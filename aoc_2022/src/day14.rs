@@ -1,10 +1,15 @@
-﻿use std::cmp::{max, min};
+﻿// aoc: title = "Regolith Reservoir", tags = [Grid, Simulation]
+
+use std::cmp::{max, min};
 use std::io::BufRead;
 use std::num::ParseIntError;
 use std::ops::{Deref, DerefMut};
 use std::str::FromStr;
+use common::geometry_2d::{Direction, Point};
+use common::grid::Grid;
 use common::iter_ext::TryIterator;
 use common::input::{Input, parse_lines};
+use common::viz::{Frame, NoViz, Observer, Recorder, Visualize};
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum Tile {
@@ -14,8 +19,7 @@ pub enum Tile {
 }
 
 const SPAWN_POINT_X: usize = 500;
-const SPAWN_POINT_Y: usize = 0;
-const SPAWN_POINT: (usize, usize) = (SPAWN_POINT_X, SPAWN_POINT_Y);
+const SPAWN_POINT: Point = Point { x: SPAWN_POINT_X as u32, y: 0 };
 const WIDTH: usize = 800;
 const HEIGHT: usize = 200;
 
@@ -29,10 +33,10 @@ pub enum Error {
     OutOfBoundsError,
 }
 
-pub struct Map([[Tile; HEIGHT]; WIDTH]);
+pub struct Map(Grid<Tile>);
 
 impl Deref for Map {
-    type Target = [[Tile; HEIGHT]; WIDTH];
+    type Target = Grid<Tile>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -48,7 +52,7 @@ impl DerefMut for Map {
 impl Input<'_> for Map {
     type Error = Error;
     fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
-        let mut data = [[Tile::Empty; HEIGHT]; WIDTH];
+        let mut data = Grid::new(WIDTH, HEIGHT, Tile::Empty);
 
         parse_lines::<Error>(&mut read, |line| {
             let points: Vec<_> = parse_line(line).try_collect2()?;
@@ -62,7 +66,8 @@ impl Input<'_> for Map {
 
                 for x in x_min..=x_max {
                     for y in y_min..=y_max {
-                        data[x][y] = Tile::Wall;
+                        let wall = data.get_mut(Point::new(x as u32, y as u32)).ok_or(Error::OutOfBoundsError)?;
+                        *wall = Tile::Wall;
                     }
                 }
             }
@@ -85,46 +90,37 @@ fn parse_line<'a>(line: &'a str) -> impl 'a + Iterator<Item=Result<(usize, usize
     })
 }
 
-fn drop_point(map: &Map, (x, y): (usize, usize)) -> Result<Option<(usize, usize)>, Error> {
-    let y = y + 1;
-
-    for x_offset in [0, -1, 1] {
-        let x = match x.checked_add_signed(x_offset) {
-            None => continue,
-            Some(x) => x,
-        };
+fn drop_point(map: &Map, point: Point) -> Result<Option<Point>, Error> {
+    for dir in [Direction::DOWN, Direction::DOWN_LEFT, Direction::DOWN_RIGHT] {
+        let Some(point) = point.offset(dir) else { continue };
 
-        if x >= WIDTH || y >= HEIGHT {
-            return Err(Error::OutOfBoundsError);
-        }
-
-        if map[x][y] == Tile::Empty {
-            return Ok(Some((x, y)));
+        match map.get(point) {
+            None => return Err(Error::OutOfBoundsError),
+            Some(Tile::Empty) => return Ok(Some(point)),
+            Some(_) => {}
         }
     }
     Ok(None)
 }
 
-#[allow(dead_code)]
-fn print_map(map: &Map) {
-    let mut string = String::with_capacity(WIDTH);
-    for y in 0..HEIGHT {
-        string.clear();
-        for x in 0..WIDTH {
-            string.push(match map[x][y] {
-                Tile::Empty => ' ',
-                Tile::Wall => '#',
-                Tile::Sand => 'o',
-            })
-        }
-        println!("{}", string);
+impl Visualize for Map {
+    /// Renders the triangle below the spawn point that sand can reach.
+    fn frame(&self) -> Frame {
+        let bottom = (0..HEIGHT).rev()
+            .find(|&y| self.row(y).contains(&Tile::Wall))
+            .unwrap_or(0);
+        let x_min = SPAWN_POINT_X.saturating_sub(bottom + 1);
+        let x_max = min(SPAWN_POINT_X + bottom + 1, WIDTH - 1);
+
+        Frame::from_fn(x_max - x_min + 1, bottom + 1, |x, y| match self[Point::new((x_min + x) as u32, y as u32)] {
+            Tile::Empty => ' ',
+            Tile::Wall => '#',
+            Tile::Sand => 'o',
+        })
     }
-    println!();
-    println!();
-    println!();
 }
 
-fn drop_sand_particle(map: &Map) -> Result<(usize, usize), Error> {
+fn drop_sand_particle(map: &Map) -> Result<Point, Error> {
     let mut point = SPAWN_POINT;
     while let Some(new_point) = drop_point(&map, point)? {
         point = new_point;
@@ -132,10 +128,18 @@ fn drop_sand_particle(map: &Map) -> Result<(usize, usize), Error> {
     Ok(point)
 }
 
-pub fn task1(mut map: Map) -> Result<u64, Error> {
+pub fn task1(map: Map) -> Result<u64, Error> {
+    task1_core(map, &mut NoViz)
+}
+
+pub fn task1_viz(map: Map, recorder: &mut Recorder) -> Result<u64, Error> {
+    task1_core(map, recorder)
+}
+
+fn task1_core(mut map: Map, observer: &mut impl Observer) -> Result<u64, Error> {
     let mut count = 0_u64;
     loop {
-        if map[SPAWN_POINT_X][SPAWN_POINT_Y] != Tile::Empty {
+        if map[SPAWN_POINT] != Tile::Empty {
             break;
         }
 
@@ -147,32 +151,42 @@ pub fn task1(mut map: Map) -> Result<u64, Error> {
             },
         };
 
-        map[point.0][point.1] = Tile::Sand;
+        map[point] = Tile::Sand;
         count += 1;
+        observer.frame(|| map.frame().with_status(format!("sand: {count}")));
     }
 
     Ok(count)
 }
 
-pub fn task2(mut map: Map) -> Result<u64, Error> {
-    let floor_height = (0..HEIGHT).rev().filter(|y| {
-        (0..WIDTH).any(|x| map[x][*y] == Tile::Wall)
+pub fn task2(map: Map) -> Result<u64, Error> {
+    task2_core(map, &mut NoViz)
+}
+
+pub fn task2_viz(map: Map, recorder: &mut Recorder) -> Result<u64, Error> {
+    task2_core(map, recorder)
+}
+
+fn task2_core(mut map: Map, observer: &mut impl Observer) -> Result<u64, Error> {
+    let floor_height = (0..HEIGHT).rev().filter(|&y| {
+        map.row(y).contains(&Tile::Wall)
     }).next().unwrap() + 2;
 
     (0..WIDTH).for_each(|x| {
-        map[x][floor_height] = Tile::Wall;
+        map[Point::new(x as u32, floor_height as u32)] = Tile::Wall;
     });
 
     let mut count = 0_u64;
     loop {
-        if map[SPAWN_POINT_X][SPAWN_POINT_Y] != Tile::Empty {
+        if map[SPAWN_POINT] != Tile::Empty {
             break;
         }
 
         let point = drop_sand_particle(&map)?;
 
-        map[point.0][point.1] = Tile::Sand;
+        map[point] = Tile::Sand;
         count += 1;
+        observer.frame(|| map.frame().with_status(format!("sand: {count}")));
     }
 
     Ok(count)
@@ -1,8 +1,17 @@
-use std::{collections::HashSet, str::FromStr};
+// aoc: title = "Beacon Exclusion Zone", tags = [Grid]
 
-use common::input::Linewise;
+use std::{collections::HashSet, num::ParseIntError, str::FromStr};
 
-pattern_parse::parse_fn!(parse_line_parts, "Sensor at x={i32}, y={i32}: closest beacon is at x={i32}, y={i32}");
+use common::input::{InputError, IntsError, IntsN, Linewise};
+use common::params::{ParamError, Params};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Parse(#[from] InputError<IntsError<ParseIntError>>),
+    #[error(transparent)]
+    Param(#[from] ParamError),
+}
 
 struct Point(i32, i32);
 
@@ -20,10 +29,11 @@ impl SensorPoint {
 }
 
 impl FromStr for SensorPoint {
-    type Err = pattern_parse::ParseError;
+    type Err = IntsError<ParseIntError>;
 
+    /// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (sx, sy, bx, by) = parse_line_parts(s)?;
+        let [sx, sy, bx, by] = s.parse::<IntsN<i32, 4>>()?.into_inner();
         let s = Point(sx, sy);
         let b = Point(bx, by);
         let range = distance(&s, &b);
@@ -35,7 +45,8 @@ fn distance(a: &Point, b: &Point) -> u32 {
     a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
 }
 
-pub fn task1(input: Linewise<SensorPoint>) -> Result<usize, pattern_parse::ParseError> {
+// aoc: slow, params = [row = 2000000], example = [row = 10]
+pub fn task1(input: Linewise<SensorPoint>, params: &Params) -> Result<usize, Error> {
     let mut sensors = Vec::new();
     let mut beacons = Vec::new();
 
@@ -45,12 +56,12 @@ pub fn task1(input: Linewise<SensorPoint>) -> Result<usize, pattern_parse::Parse
         beacons.push(b);
     });
 
-    const CHECK_Y: i32 = 2000000;
+    let check_y: i32 = params.get("row")?;
 
     let mut coverage = HashSet::new();
 
     for sensor in sensors {
-        let y_dist = sensor.pos.1.abs_diff(CHECK_Y);
+        let y_dist = sensor.pos.1.abs_diff(check_y);
 
         // line is completely out of range
         if y_dist > sensor.range {
@@ -67,7 +78,7 @@ pub fn task1(input: Linewise<SensorPoint>) -> Result<usize, pattern_parse::Parse
     }
 
     for b in beacons {
-        if b.1 == CHECK_Y {
+        if b.1 == check_y {
             coverage.remove(&b.0);
         }
     }
@@ -75,7 +86,8 @@ pub fn task1(input: Linewise<SensorPoint>) -> Result<usize, pattern_parse::Parse
     Ok(coverage.len())
 }
 
-pub fn task2(input: Linewise<SensorPoint>) -> Result<u64, pattern_parse::ParseError> {
+// aoc: params = [max = 4000000], example = [max = 20]
+pub fn task2(input: Linewise<SensorPoint>, params: &Params) -> Result<u64, Error> {
     let mut sensors = Vec::new();
     let mut beacons = Vec::new();
 
@@ -85,9 +97,9 @@ pub fn task2(input: Linewise<SensorPoint>) -> Result<u64, pattern_parse::ParseEr
         beacons.push(b);
     });
 
-    const MAX_DIST: i32 = 4_000_000;
+    let max_dist: i32 = params.get("max")?;
 
-    for y in 0..MAX_DIST {
+    for y in 0..max_dist {
         let mut coverage = Vec::new();
 
         for sensor in &sensors {
@@ -108,7 +120,7 @@ pub fn task2(input: Linewise<SensorPoint>) -> Result<u64, pattern_parse::ParseEr
 
         let mut x = 0;
         loop {
-            if x >= MAX_DIST {
+            if x >= max_dist {
                 break;
             }
 
@@ -131,3 +143,43 @@ pub fn task2(input: Linewise<SensorPoint>) -> Result<u64, pattern_parse::ParseEr
     }
     panic!("NONE FOUND");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::Input;
+
+    const INPUT: &[u8] = b"\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    fn example_params(task: &str) -> Params {
+        crate::YEAR.day("day15").unwrap().task(task).unwrap().example_params()
+    }
+
+    #[test]
+    fn test_task1() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task1(Input::parse(buf).unwrap(), &example_params("task1"));
+        assert_eq!(result.unwrap(), 26);
+    }
+
+    #[test]
+    fn test_task2() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task2(Input::parse(buf).unwrap(), &example_params("task2"));
+        assert_eq!(result.unwrap(), 56000011);
+    }
+}
@@ -1,17 +1,24 @@
+// aoc: title = "Proboscidea Volcanium", tags = [Pathfinding]
+
 use std::collections::HashSet;
 use std::iter::Cloned;
-use std::{str::FromStr, num::ParseIntError, collections::HashMap};
+use std::collections::HashMap;
 
 use rayon::prelude::{ParallelIterator, IntoParallelIterator};
 
 use  common::iter_ext::TryIterator;
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
+use pattern_parse::PatternParse;
 use  common::pathfinding::{self as pf, Algorithm};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("Invalid valve name '{0}'")]
+    InvalidName(String),
+    #[error("Invalid tunnel list '{0}'")]
+    InvalidTunnels(String),
     #[error(transparent)]
-    ParseIntError(#[from] ParseIntError)
+    Parse(#[from] InputError<pattern_parse::ParseError>),
 }
 
 fn encode_name(s: &str) -> u16 {
@@ -24,42 +31,32 @@ fn encode_name(s: &str) -> u16 {
     }
 }
 
-fn split_while(s: &str, mut predicate: impl FnMut(char) -> bool) -> &str {
-    let res = s.char_indices()
-        .take_while(|(_i,c)| predicate(*c))
-        .last();
-    match res {
-        Some((i,_)) => &s[..=i],
-        None => &s[0..0],
+fn parse_name(s: &str) -> Result<u16, Error> {
+    if s.len() == 2 && s.is_ascii() {
+        Ok(encode_name(s))
+    } else {
+        Err(Error::InvalidName(s.to_owned()))
     }
 }
 
-#[derive(Debug)]
+fn parse_tunnels(s: &str) -> Result<Vec<u16>, Error> {
+    let names = s
+        .strip_prefix("tunnels lead to valves ")
+        .or_else(|| s.strip_prefix("tunnel leads to valve "))
+        .ok_or_else(|| Error::InvalidTunnels(s.to_owned()))?;
+    names.rsplit(", ").map(parse_name).collect()
+}
+
+#[derive(Debug, PatternParse)]
+#[pattern("Valve {name} has flow rate={flow_rate}; {connected}")]
 pub struct Valve {
+    #[pattern(with = parse_name)]
     name: u16,
     flow_rate: usize,
+    #[pattern(with = parse_tunnels)]
     connected: Vec<u16>,
 }
 
-impl FromStr for Valve {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let name = &s[6..8];
-        let name = encode_name(name);
-
-        let flow_rate = split_while(&s[23..], char::is_numeric);
-        let flow_rate = flow_rate.parse()?;
-
-        let connected = s[49..].rsplit(", ").map(|s| {
-                    let len = s.len();
-                    encode_name(&s[len-2..len])
-                }).collect::<Vec<_>>();
-
-        Ok(Valve{name, flow_rate, connected})
-    }
-}
-
 impl PartialEq for Valve {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
@@ -69,7 +66,7 @@ impl PartialEq for Valve {
 impl Eq for Valve {}
 
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     valves: HashMap<u16, Valve>,
     non_zero: Vec<u16>,
     distances: HashMap<u32, usize>,
@@ -162,10 +159,12 @@ fn traverse_masked_map<const MAX: usize>(step: usize, valve: &Valve, mut acc: us
     best
 }
 
-pub fn task1(valves: Linewise<Valve>) -> Result<usize, Error> {
+pub fn parse(valves: Linewise<Valve>) -> Result<Map, Error> {
     let valves = valves.try_collect2()?;
-    let map = Map::from_valves(valves);
+    Ok(Map::from_valves(valves))
+}
 
+pub fn task1(map: &Map) -> Result<usize, Error> {
     let mut visited = HashSet::new();
     let root_name = encode_name("AA");
     let root_node = &map.valves[&root_name];
@@ -175,10 +174,8 @@ pub fn task1(valves: Linewise<Valve>) -> Result<usize, Error> {
     Ok(result)
 }
 
-pub fn task2(valves: Linewise<Valve>) -> Result<usize, Error> {
-    let valves = valves.try_collect2()?;
-    let map = Map::from_valves(valves);
-
+// aoc: slow
+pub fn task2(map: &Map) -> Result<usize, Error> {
     let root_name = encode_name("AA");
     let root_node = &map.valves[&root_name];
 
@@ -202,4 +199,36 @@ pub fn task2(valves: Linewise<Valve>) -> Result<usize, Error> {
     }).max().unwrap();
 
     Ok(result)
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use common::input::Input;
+    use super::*;
+
+    const INPUT: &[u8] = b"\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn parses_valves() {
+        let valves = Linewise::<Valve>::parse(INPUT).unwrap().try_collect2::<Vec<_>>().unwrap();
+        assert_eq!(valves.len(), 10);
+        assert_eq!((valves[0].name, valves[0].flow_rate), (encode_name("AA"), 0));
+        assert_eq!(valves[0].connected, ["BB", "II", "DD"].map(encode_name));
+        assert_eq!((valves[7].name, valves[7].flow_rate), (encode_name("HH"), 22));
+        assert_eq!(valves[7].connected, [encode_name("GG")]);
+    }
+    #[test]
+    fn rejects_invalid_tunnels() {
+        let err = "Valve AA has flow rate=0; pipes lead to BB".parse::<Valve>().unwrap_err();
+        assert!(err.to_string().contains("pipes lead to BB"));
+    }
+}
@@ -1,18 +1,21 @@
+// aoc: title = "Pyroclastic Flow", tags = [Grid, Simulation]
+
 use std::collections::hash_map::Entry;
 
 use common::{
     iter_ext::TryIterator,
     input::{
-        chars::{Charwise, FromChar},
+        chars::{Charwise, FromChar, UnexpectedChar},
         lines::Linewise,
     },
+    viz::{Frame, NoViz, Observer, Recorder, Visualize},
 };
 use ahash::*;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Unknown shift char '{0}'")]
-    UnknownShift(char),
+    #[error(transparent)]
+    UnknownShift(#[from] UnexpectedChar),
 }
 
 type Row = u8;
@@ -57,22 +60,13 @@ const SHAPES: [Shape<'static>; 5] = [
     ],
 ];
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, FromChar)]
 pub enum Shift {
+    #[char('<')]
     Left,
+    #[char('>')]
     Right,
 }
-
-impl FromChar for Shift {
-    type Err = Error;
-    fn from_char(c: char) -> Result<Self, Self::Err> {
-        match c {
-            '<' => Ok(Self::Left),
-            '>' => Ok(Self::Right),
-            c => Err(Error::UnknownShift(c)),
-        }
-    }
-}
 const WIDTH: u8 = 7;
 const LEFT_EDGE: u8 = 1 << (WIDTH - 1);
 const RIGHT_EDGE: u8 = 1;
@@ -163,7 +157,31 @@ fn drop_shape(
     }
 }
 
+/// Number of rows at the top of the tower included in visualization frames.
+const VIEW_HEIGHT: usize = 40;
+
+/// View onto the topmost rows of the tower.
+struct Tower<'a>(&'a [u8]);
+
+impl Visualize for Tower<'_> {
+    fn frame(&self) -> Frame {
+        let top = self.0.len();
+        Frame::from_fn(WIDTH as usize, VIEW_HEIGHT, |x, y| {
+            let row = top.checked_sub(y + 1).and_then(|y| self.0.get(y)).copied().unwrap_or(0);
+            if row & (LEFT_EDGE >> x) != 0 { '#' } else { '.' }
+        })
+    }
+}
+
 pub fn task1(chars: Linewise<Charwise<Shift>>) -> Result<usize, Error> {
+    task1_core(chars, &mut NoViz)
+}
+
+pub fn task1_viz(chars: Linewise<Charwise<Shift>>, recorder: &mut Recorder) -> Result<usize, Error> {
+    task1_core(chars, recorder)
+}
+
+fn task1_core(chars: Linewise<Charwise<Shift>>, observer: &mut impl Observer) -> Result<usize, Error> {
     let shapes = SHAPES.iter().cycle();
     let shifts: Vec<_> = chars.flat_map(|r| r.unwrap()).try_collect2()?;
     let mut shift_index = 0;
@@ -171,9 +189,10 @@ pub fn task1(chars: Linewise<Charwise<Shift>>) -> Result<usize, Error> {
     let mut occupied = vec![];
     let mut max_y = 0;
 
-    for shape in shapes.take(2022).map(|s| s.to_vec()) {
+    for (i, shape) in shapes.take(2022).map(|s| s.to_vec()).enumerate() {
         // move the shape 3 units above the highest occupied tile
         drop_shape(shape, &shifts, &mut shift_index, &mut occupied, &mut max_y);
+        observer.frame(|| Tower(&occupied).frame().with_status(format!("rocks: {} height: {max_y}", i + 1)));
     }
 
     Ok(max_y)
@@ -272,23 +291,4 @@ mod tests {
         let total = task2(chars).unwrap();
         assert_eq!(total, 1514285714288_u64);
     }
-
-    #[allow(dead_code)]
-    fn print_map(occupied: &Vec<u8>, height: usize) {
-        let min = occupied.len().checked_sub(height).unwrap_or(0);
-        let max = min + height;
-        let mut row_str = String::with_capacity(8);
-        for y in (min..max).rev() {
-            row_str.clear();
-            let row = occupied.get(y).cloned().unwrap_or(0);
-            for x in (0..WIDTH).rev() {
-                if row & (1 << x) != 0 {
-                    row_str.push('#');
-                } else {
-                    row_str.push('.');
-                }
-            }
-            println!("{}", row_str);
-        }
-    }
 }
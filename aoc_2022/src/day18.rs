@@ -1,15 +1,21 @@
+// aoc: title = "Boiling Boulders", tags = [Grid]
+
 use std::{collections::HashSet, str::FromStr, num::ParseIntError};
 
-use common::{pathfinding as pf, iter_ext::TryIterator, input::Linewise};
+use common::{pathfinding as pf, iter_ext::TryIterator, input::{InputError, Linewise}};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Missing line {0}")]
     MissingLine(usize),
     #[error(transparent)]
-    ParseIntError(#[from] ParseIntError)
+    ParseIntError(#[from] ParseIntError),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 struct World {
     points: Vec<Point>,
     keys: HashSet<u32>,
@@ -128,6 +134,7 @@ pub fn task1(points: Linewise<Point>) -> Result<u64, Error>{
     Ok(count)
 }
 
+// aoc: slow
 pub fn task2(points: Linewise<Point>) -> Result<u64, Error>{
     let world = World::new(points.try_collect2()?);
     let agent = Agent;
@@ -1,11 +1,13 @@
+// aoc: title = "Not Enough Minerals", tags = [Simulation]
+
 use std::{
+    num::ParseIntError,
     ops::{Add, AddAssign, Sub, SubAssign},
     str::FromStr,
 };
 
-use pattern_parse::parse_fn;
 use rayon::prelude::*;
-use common::input::Linewise;
+use common::input::{InputError, IntsError, IntsN, Linewise};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 struct Resources {
@@ -68,23 +70,19 @@ impl Resources {
 
 #[derive(Debug)]
 pub struct Blueprint {
-    id: u8,
+    id: usize,
     ore_cost: Resources,
     clay_cost: Resources,
     obsidian_cost: Resources,
     geode_cost: Resources,
 }
-parse_fn!(
-    parse_blueprint_line,
-    "Blueprint {u8}: Each ore robot costs {usize} ore. Each clay robot costs {usize} ore. Each obsidian robot costs {usize} ore and {usize} clay. Each geode robot costs {usize} ore and {usize} obsidian."
-);
-
 impl FromStr for Blueprint {
-    type Err = pattern_parse::ParseError;
+    type Err = IntsError<ParseIntError>;
 
+    /// `Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. ...`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) =
-            parse_blueprint_line(s)?;
+        let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+            s.parse::<IntsN<usize, 7>>()?.into_inner();
 
         Ok(Blueprint {
             id,
@@ -237,20 +235,22 @@ fn run(bps: Vec<Blueprint>, steps: usize) -> usize {
     bps.into_par_iter()
         .map(|blueprint| {
             let score = run_blueprint(&blueprint, steps);
-            let qs = score * blueprint.id as usize;
+            let qs = score * blueprint.id;
             qs
         })
         .sum::<usize>()
 }
 
-pub fn task1(input: Linewise<Blueprint>) -> Result<usize, pattern_parse::ParseError> {
+// aoc: slow
+pub fn task1(input: Linewise<Blueprint>) -> Result<usize, InputError<IntsError<ParseIntError>>> {
     let mut bps = Vec::new();
     common::for_input!(input, |bp| { bps.push(bp) });
     let result = run(bps, 24);
     Ok(result)
 }
 
-pub fn task2(input: Linewise<Blueprint>) -> Result<usize, pattern_parse::ParseError> {
+// aoc: slow
+pub fn task2(input: Linewise<Blueprint>) -> Result<usize, InputError<IntsError<ParseIntError>>> {
     let mut bps = Vec::new();
     common::for_input!(input, |bp| { bps.push(bp) });
     let result = run(bps, 32);
@@ -1,6 +1,8 @@
-use common::{iter_ext::TryIterator, input::Linewise};
+// aoc: title = "Grove Positioning System", tags = [Simulation]
 
-pub fn task1(input: Linewise<i128>) -> Result<i128, std::num::ParseIntError> {
+use common::{iter_ext::TryIterator, input::{InputError, Linewise}};
+
+pub fn task1(input: Linewise<i128>) -> Result<i128, InputError<std::num::ParseIntError>> {
     let mut nums = vec![];
 
     common::for_input!(input, |i| {
@@ -18,7 +20,7 @@ pub fn task1(input: Linewise<i128>) -> Result<i128, std::num::ParseIntError> {
     Ok(a + b + c)
 }
 
-pub fn task2(input: Linewise<i128>) -> Result<i128, std::num::ParseIntError> {
+pub fn task2(input: Linewise<i128>) -> Result<i128, InputError<std::num::ParseIntError>> {
     const DECRYPT_KEY: i128 = 811589153;
 
     let original: Vec<_> = input.try_collect2()?;
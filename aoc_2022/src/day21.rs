@@ -1,9 +1,11 @@
+// aoc: title = "Monkey Math", tags = [Parsing]
+
 use std::{
     collections::HashMap,
     str::FromStr, num::ParseIntError,
 };
 
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -11,8 +13,12 @@ pub enum Error {
     ParseIntError(#[from] ParseIntError),
     #[error("Unknown operation '{0}'")]
     UnknownOperation(String),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 #[derive(Debug, Clone, Copy)]
 pub struct NamedExpression(u32, Expression);
 
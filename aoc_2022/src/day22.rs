@@ -1,30 +1,25 @@
+// aoc: title = "Monkey Map", tags = [Grid, Simulation]
+
 use std::ops::Deref;
 
+use common::input::{FromByte, FromChar};
 use grid::Grid;
 
 /// Enumeration covering all types of tiles
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromChar)]
 enum Tile {
     /// A blank tile represented in the input as ' '. It is itself not traversable but it
     /// possible to wrap around the "opposing" tile is `Free`
+    #[char(' ')]
     Blank,
     /// A non-traversable tile represented in the input as '#'.
+    #[char('#')]
     Wall,
     /// A traversable tile represented in the input as '.'.
+    #[char('.')]
     Free,
 }
 
-impl From<u8> for Tile {
-    fn from(value: u8) -> Self {
-        match value {
-            b' ' => Self::Blank,
-            b'#' => Self::Wall,
-            b'.' => Self::Free,
-            _ => panic!("Invalid tile: {}", value as char),
-        }
-    }
-}
-
 impl Default for Tile {
     fn default() -> Self {
         Self::Blank
@@ -97,12 +92,7 @@ fn parse_map(s: &str) -> Map {
         .map(str::trim_end)
         .take_while(|s| s.len() > 0)
         .for_each(|line| {
-            let new_tiles = line.chars().filter_map(|c| match c {
-                ' ' => Some(Tile::Blank),
-                '#' => Some(Tile::Wall),
-                '.' => Some(Tile::Free),
-                _ => None,
-            });
+            let new_tiles = line.bytes().filter_map(|b| Tile::from_byte(b).ok());
             let padding = line_len - line.len();
             let padding = std::iter::repeat(Tile::Blank).take(padding);
             tiles.extend(new_tiles.chain(padding));
@@ -1,4 +1,7 @@
-﻿use common::input::Input;
+﻿// aoc: title = "Unstable Diffusion", tags = [Grid, Simulation]
+
+use common::input::Input;
+use common::viz::{Frame, NoViz, Observer, Recorder, Visualize};
 use bitflags::bitflags;
 use std::collections::hash_map::Entry;
 use std::convert::Infallible;
@@ -147,6 +150,18 @@ impl Display for Map {
     }
 }
 
+impl Visualize for Map {
+    fn frame(&self) -> Frame {
+        let (x_min, x_max, y_min, y_max) = self.get_bounds();
+        let width = (x_max - x_min + 1) as usize;
+        let height = (y_max - y_min + 1) as usize;
+        Frame::from_fn(width, height, |x, y| {
+            let elf = Elf { x: x_min + x as i32, y: y_min + y as i32 };
+            if self.0.contains(&elf) { '#' } else { '.' }
+        })
+    }
+}
+
 impl<'a> Input<'a> for Map {
     type Error = Error;
 
@@ -231,20 +246,40 @@ impl Map {
     }
 }
 
-pub fn task1(mut map: Map) -> Result<i32, Infallible> {
+pub fn task1(map: Map) -> Result<i32, Infallible> {
+    task1_core(map, &mut NoViz)
+}
+
+pub fn task1_viz(map: Map, recorder: &mut Recorder) -> Result<i32, Infallible> {
+    task1_core(map, recorder)
+}
+
+fn task1_core(mut map: Map, observer: &mut impl Observer) -> Result<i32, Infallible> {
+    observer.frame(|| map.frame().with_status("initial state"));
     for i in 0..10 {
         map.step(i);
+        observer.frame(|| map.frame().with_status(format!("round: {}", i + 1)));
     }
 
     let free = map.get_occupied_tile_count();
     Ok(free)
 }
 
-pub fn task2(mut map: Map) -> Result<usize, Infallible> {
+pub fn task2(map: Map) -> Result<usize, Infallible> {
+    task2_core(map, &mut NoViz)
+}
+
+pub fn task2_viz(map: Map, recorder: &mut Recorder) -> Result<usize, Infallible> {
+    task2_core(map, recorder)
+}
+
+fn task2_core(mut map: Map, observer: &mut impl Observer) -> Result<usize, Infallible> {
+    observer.frame(|| map.frame().with_status("initial state"));
     let mut round = 0;
     loop {
         let any_movement = map.step(round);
         round += 1;
+        observer.frame(|| map.frame().with_status(format!("round: {round}")));
 
         if any_movement == false {
             break;
@@ -1,3 +1,5 @@
+// aoc: title = "Blizzard Basin", tags = [Grid, Pathfinding]
+
 use std::{
     cell::{RefCell, RefMut},
     mem::swap,
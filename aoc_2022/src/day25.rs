@@ -1,14 +1,21 @@
-use std::{iter::Sum, fmt::{Display, Write}, str::FromStr, convert::Infallible};
+// aoc: title = "Full of Hot Air", tags = [NumberTheory]
 
-use common::input::Linewise;
+use std::{iter::Sum, fmt::{Display, Write}, str::FromStr};
+
+use common::input::{FromChar, InputError, Linewise, UnexpectedChar};
 
 #[repr(i64)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, FromChar)]
 enum Digit {
+    #[char('=')]
     DoubleMinus = -2,
+    #[char('-')]
     Minus = -1,
+    #[char('0')]
     Zero = 0,
+    #[char('1')]
     One = 1,
+    #[char('2')]
     Two = 2,
 }
 
@@ -33,19 +40,6 @@ static ALL_DIGIS: [Digit; 5] = [
     Digit::Two,
 ];
 
-impl From<char> for Digit {
-    fn from(value: char) -> Self {
-        match value {
-            '=' => Self::DoubleMinus,
-            '-' => Self::Minus,
-            '0' => Self::Zero,
-            '1' => Self::One,
-            '2' => Self::Two,
-            _ => panic!("Invalid char {value}"),
-        }
-    }
-}
-
 impl Digit {
     fn positional_value(self, pos: usize) -> i64 {
         5_i64.pow(pos as u32) * (self as i64)
@@ -62,10 +56,11 @@ impl From<Digit> for i64 {
 pub struct Number(Vec<Digit>);
 
 impl FromStr for Number {
-    type Err = Infallible;
+    type Err = UnexpectedChar;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Number(s.chars().map(Digit::from).collect()))
+        let digits = s.chars().map(Digit::from_char).collect::<Result<_, _>>()?;
+        Ok(Number(digits))
     }
 }
 
@@ -126,7 +121,7 @@ impl From<i64> for Number {
     }
 }
 
-pub fn task1(input: Linewise<Number>) -> Result<Number, Infallible> {
+pub fn task1(input: Linewise<Number>) -> Result<Number, InputError<UnexpectedChar>> {
     let mut sum: i64 = 0;
     common::for_input!(input, |num| { sum += i64::from(num) });
     Ok(sum.into())
@@ -1,27 +1 @@
-common::decl_year! {
-    #[doc("../docs/day01.md")] day01 {task1;task2;}
-    #[doc("../docs/day02.md")] day02 {task1;task2;}
-    #[doc("../docs/day03.md")] day03 {task1;task2;}
-    #[doc("../docs/day04.md")] day04 {task1;task2;}
-    #[doc("../docs/day05.md")] day05 {task1;task2;}
-    #[doc("../docs/day06.md")] day06 {task1;task2;}
-    #[doc("../docs/day07.md")] day07 {task1;task2;}
-    #[doc("../docs/day08.md")] day08 {task1;task2;}
-    #[doc("../docs/day09.md")] day09 {task1;task2;}
-    #[doc("../docs/day10.md")] day10 {task1;task2;}
-    #[doc("../docs/day11.md")] day11 {task1;task2;}
-    #[doc("../docs/day12.md")] day12 {task1;task2;}
-    #[doc("../docs/day13.md")] day13 {task1;task2;}
-    #[doc("../docs/day14.md")] day14 {task1;task2;}
-    #[doc("../docs/day15.md")] day15 {task1;task2;}
-    #[doc("../docs/day16.md")] day16 {task1;task2;}
-    #[doc("../docs/day17.md")] day17 {task1;task2;}
-    #[doc("../docs/day18.md")] day18 {task1;task2;}
-    #[doc("../docs/day19.md")] day19 {task1;task2;}
-    #[doc("../docs/day20.md")] day20 {task1;task2;}
-    #[doc("../docs/day21.md")] day21 {task1;task2;}
-    #[doc("../docs/day22.md")] day22 {task1;task2;}
-    #[doc("../docs/day23.md")] day23 {task1;task2;}
-    #[doc("../docs/day24.md")] day24 {task1;task2;}
-    #[doc("../docs/day25.md")] day25 {task1;}
-}
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -1,4 +1,4 @@
-use common::input::Linewise;
+// aoc: title = "Trebuchet?!", tags = [Parsing]
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -7,10 +7,9 @@ pub enum Error {
 }
 
 /// Find and parse the first and last digit in each line
-pub fn task1(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task1(input: &str) -> Result<u32, Error> {
     let mut total = 0u32;
-    for (i, line) in input.enumerate() {
-        let line = line.unwrap();
+    for (i, line) in input.lines().enumerate() {
 
         let first = line.chars()
             .filter_map(|c| c.to_digit(10))
@@ -61,10 +60,9 @@ fn try_read_number(s: &str) -> Option<u32> {
 }
 
 /// Find and parse the first and last number (digit or written) in each line
-pub fn task2(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task2(input: &str) -> Result<u32, Error> {
     let mut total = 0u32;
-    for line in input {
-        let line = line.unwrap();
+    for line in input.lines() {
         let mut cursor = 0;
 
         let first = loop {
@@ -95,27 +93,27 @@ mod tests {
 
     #[test]
     fn test_task1() {
-        let buf = std::io::BufReader::new(b"\
+        let buf = b"\
 1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
-treb7uchet".as_slice());
-        let result = task1(Input::parse(buf).unwrap());
+treb7uchet".as_slice();
+        let result = task1(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 142);
     }
 
     #[test]
     fn test_task2() {
-        let buf = std::io::BufReader::new(b"\
+        let buf = b"\
 two1nine
 eightwothree
 abcone2threexyz
 xtwone3four
 4nineeightseven2
 zoneight234
-7pqrstsixteen".as_slice());
-        let result = task2(Input::parse(buf).unwrap());
+7pqrstsixteen".as_slice();
+        let result = task2(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 281);
     }
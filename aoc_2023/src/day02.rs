@@ -1,14 +1,14 @@
+// aoc: title = "Cube Conundrum", tags = [Parsing]
+
 use std::cmp::max;
-use common::input::Linewise;
 use std::str::FromStr;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
-pub fn task1(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task1(input: &str) -> Result<u32, Error> {
     let mut total = 0;
-    for (i, line) in input.enumerate() {
-        let line = line.unwrap();
+    for (i, line) in input.lines().enumerate() {
 
         // skip the prefix of Game XY:
         let valid = line.split_once(':').unwrap()
@@ -38,11 +38,9 @@ pub fn task1(input: Linewise<String>) -> Result<u32, Error> {
     Ok(total)
 }
 
-pub fn task2(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task2(input: &str) -> Result<u32, Error> {
     let mut total = 0;
-    for line in input {
-        let line = line.unwrap();
-
+    for line in input.lines() {
         // skip the prefix of Game XY:
         let groups = line.split_once(':').unwrap().1;
 
@@ -82,16 +80,16 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
     #[test]
     fn test_task1() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task1(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task1(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 8);
     }
 
     #[test]
     fn test_task2() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task2(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task2(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 2286);
     }
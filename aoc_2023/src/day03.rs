@@ -1,3 +1,5 @@
+// aoc: title = "Gear Ratios", tags = [Grid]
+
 use std::cmp::Ordering;
 use std::num::ParseIntError;
 use std::str::FromStr;
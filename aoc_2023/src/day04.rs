@@ -1,18 +1,27 @@
+// aoc: title = "Scratchcards"
+
+use common::input::{InputError, KeyValue, KeyValueError, Linewise};
+use std::convert::Infallible;
+use std::num::ParseIntError;
 use std::ops::BitAnd;
 use std::str::FromStr;
-use common::input::Linewise;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
-    #[error("Missing delimiter '{0}' in input line")]
-    MissingDelimiter(char),
     #[error(transparent)]
-    ParseInt(#[from] std::num::ParseIntError),
+    Card(#[from] InputError<KeyValueError<Infallible, KeyValueError<ParseIntError, ParseIntError>>>),
 }
 
+common::separator!(pub Bar = " | ");
+
+/// The winning numbers and the numbers you have.
+type Numbers = KeyValue<Bits, Bits, Bar>;
+/// A line like `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+type Card = KeyValue<String, Numbers>;
+
 /// Container for (at least) 100 bit, to represent a bitmask of all mentioned numbers
 #[derive(Debug, Default, Copy, Clone)]
-struct Bits([u64; 2]);
+pub struct Bits([u64; 2]);
 
 impl Bits {
     pub fn set(&mut self, i: usize) {
@@ -27,6 +36,19 @@ impl Bits {
     }
 }
 
+/// The bit mask of whitespace separated numbers.
+impl FromStr for Bits {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bits = Bits::default();
+        for i in s.split_whitespace().map(usize::from_str) {
+            bits.set(i?);
+        }
+        Ok(bits)
+    }
+}
+
 impl BitAnd for Bits {
     type Output = Self;
     fn bitand(self, rhs: Self) -> Self::Output {
@@ -36,34 +58,10 @@ impl BitAnd for Bits {
     }
 }
 
-fn parse_card(line: &str) -> Result<(Bits, Bits), Error> {
-    // skip the Card id section
-    let numbers = line.split_once(": ")
-        .ok_or(Error::MissingDelimiter(':'))?.1;
-
-    // split the number into winning numbers and 'numbers you have'
-    let (win_str, owned_str) = numbers.split_once(" | ")
-        .ok_or(Error::MissingDelimiter('|'))?;
-
-    // convert the numbers into bit masks
-    let mut winning = Bits::default();
-    for i in win_str.split_whitespace().map(usize::from_str) {
-        winning.set(i?);
-    }
-
-    let mut owned = Bits::default();
-    for i in owned_str.split_whitespace().map(usize::from_str) {
-        owned.set(i?);
-    }
-    Ok((winning, owned))
-}
-
-pub fn task1(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task1(input: Linewise<Card>) -> Result<u32, Error> {
     let mut total = 0;
-    for line in input {
-        let line = line.unwrap();
-
-        let (winning, owned) = parse_card(&line)?;
+    for card in input {
+        let (winning, owned) = card?.value.into_inner();
 
         let matches = (winning & owned).count_ones();
         if let Some(shift) = matches.checked_sub(1) {
@@ -74,14 +72,12 @@ pub fn task1(input: Linewise<String>) -> Result<u32, Error> {
     Ok(total)
 }
 
-pub fn task2(input: Linewise<String>) -> Result<u32, Error> {
+pub fn task2(input: Linewise<Card>) -> Result<u32, Error> {
     let mut total = 0;
     // array containing the number of times the next 10 numbers will be copied
     // since there are only 10 winning numbers copies will never be considered more than 10 cards ahead
     let mut next_copies = [0;10];
-    for line in input {
-        let line = line.unwrap();
-
+    for card in input {
         // count the current card and its copies
         let card_count = 1 + next_copies[0];
         total += card_count;
@@ -89,7 +85,7 @@ pub fn task2(input: Linewise<String>) -> Result<u32, Error> {
         next_copies[0] = 0;
         next_copies.rotate_left(1);
 
-        let (winning, owned) = parse_card(&line)?;
+        let (winning, owned) = card?.value.into_inner();
 
         // add copies gained by the matches
         let matches = (winning & owned).count_ones();
@@ -115,16 +111,16 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn test_task1() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task1(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task1(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 13);
     }
 
     #[test]
     fn test_task2() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task2(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task2(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 30);
     }
@@ -1,4 +1,6 @@
-use common::input::Linewise;
+// aoc: title = "If You Give A Seed A Fertilizer"
+
+use common::input::{Headed, InputError, Linewise, SectionError, Sections};
 use common::iter_ext::TryIterator;
 use std::cmp::{min, Ordering};
 use std::fmt::Debug;
@@ -13,7 +15,9 @@ pub enum Error {
     #[error(transparent)]
     ParseInt(#[from] ParseIntError),
     #[error(transparent)]
-    PatternParse(#[from] pattern_parse::ParseError),
+    Range(#[from] InputError<pattern_parse::ParseError>),
+    #[error(transparent)]
+    Sections(#[from] SectionError),
 }
 
 #[derive(Debug)]
@@ -57,59 +61,55 @@ impl RangeLine {
     }
 }
 
+/// The seeds followed by the maps with their ranges sorted by their end.
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Map>,
+}
+
 pattern_parse::parse_fn!(parse_range, "{u64} {u64} {u64}");
 
-fn parse_input(input: &mut Linewise<String>) -> Result<(Vec<u64>, Vec<Map>), Error> {
-    let seed_line = input.next().expect("Failed to read seed-line").unwrap();
+impl FromStr for RangeLine {
+    type Err = pattern_parse::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (dest_start, src_start, len) = parse_range(s)?;
+        let range = src_start..(src_start + len);
+        let offset = dest_start as i64 - src_start as i64;
+        Ok(RangeLine { range, offset })
+    }
+}
+
+type Maps<'a> = Sections<Vec<Headed<String, Linewise<'a, RangeLine>>>>;
+
+pub fn parse(input: Sections<(String, Maps)>) -> Result<Almanac, Error> {
+    let (seed_line, maps) = input.into_inner();
     let (_, seed_str) = seed_line
         .split_once(':')
         .ok_or(Error::MissingDelimiter(':'))?;
-    let x = seed_str
+    let seeds = seed_str
         .split_ascii_whitespace()
         .map(u64::from_str)
         .try_collect2::<Vec<_>>()?;
 
-    // blank line
-    let _ = input.next();
-    let mut maps = vec![];
-
-    'outer: loop {
-        // header line
-        let _ = input.next();
-
-        maps.push(Map { ranges: vec![] });
-
-        let map = maps.last_mut().unwrap();
-
-        loop {
-            match input.next() {
-                None => break 'outer,
-                Some(s) => {
-                    let s = s.unwrap();
-                    if s.len() == 0 {
-                        break;
-                    }
-                    let (dest_start, src_start, len) = parse_range(&s)?;
-                    let range = src_start..(src_start + len);
-                    let offset = dest_start as i64 - src_start as i64;
-                    map.ranges.push(RangeLine { range, offset });
-                }
-            }
-        }
-    }
-    Ok((x, maps))
+    let maps = maps
+        .into_inner()
+        .into_iter()
+        .map(|map| {
+            let mut ranges = map.body.try_collect2::<Vec<_>>()?;
+            ranges.sort_by(|a, b| a.range.end.cmp(&b.range.end));
+            Ok::<_, Error>(Map { ranges })
+        })
+        .try_collect2()?;
+    Ok(Almanac { seeds, maps })
 }
 
-pub fn task1(mut input: Linewise<String>) -> Result<u64, Error> {
-    let (seeds, mut maps) = parse_input(&mut input)?;
-
-    maps.iter_mut()
-        .for_each(|m| m.ranges.sort_by(|a, b| a.range.end.cmp(&b.range.end)));
-
+pub fn task1(almanac: &Almanac) -> Result<u64, Error> {
     let mut min_location = u64::MAX;
 
-    for location in seeds {
-        let location = translate_point(&mut maps, location);
+    for &location in &almanac.seeds {
+        let location = translate_point(&almanac.maps, location);
         min_location = min(min_location, location);
     }
 
@@ -134,16 +134,13 @@ fn translate_point(maps: &Vec<Map>, mut location: u64) -> u64 {
     location
 }
 
-pub fn task2(mut input: Linewise<String>) -> Result<u64, Error> {
-    let (seeds, mut maps) = parse_input(&mut input)?;
-    maps.iter_mut()
-        .for_each(|m| m.ranges.sort_by(|a, b| a.range.end.cmp(&b.range.end)));
+pub fn task2(almanac: &Almanac) -> Result<u64, Error> {
     let mut min_location = u64::MAX;
 
-    for range in seeds.chunks_exact(2) {
+    for range in almanac.seeds.chunks_exact(2) {
         let &[start, len] = range else { unreachable!() };
         let mut ranges = vec![(start, len)];
-        for map in &maps {
+        for map in &almanac.maps {
             ranges = translate_ranges(map, ranges);
         }
         let range_min = ranges.iter().map(|r| r.0).min().unwrap();
@@ -228,7 +225,7 @@ humidity-to-location map:
     #[test]
     fn test_task1() {
         let buf = std::io::BufReader::new(INPUT);
-        let result = task1(Input::parse(buf).unwrap());
+        let result = task1(&parse(Input::parse(buf).unwrap()).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 35);
     }
@@ -236,7 +233,7 @@ humidity-to-location map:
     #[test]
     fn test_task2() {
         let buf = std::io::BufReader::new(INPUT);
-        let result = task2(Input::parse(buf).unwrap());
+        let result = task2(&parse(Input::parse(buf).unwrap()).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 46);
     }
@@ -1,5 +1,7 @@
+// aoc: title = "Wait For It", tags = [NumberTheory]
+
 use std::num::ParseIntError;
-use common::input::Linewise;
+use common::input::{Columns, Fields, InputError, LabelColumn};
 use std::str::FromStr;
 
 #[derive(Debug, thiserror::Error)]
@@ -8,6 +10,15 @@ pub enum Error {
     MissingLine(&'static str),
     #[error(transparent)]
     ParseInt(#[from] ParseIntError),
+    #[error(transparent)]
+    Number(#[from] InputError<ParseIntError>),
+}
+
+/// The races as columns below the `Time:` and `Distance:` labels.
+type Races = Columns<Fields, LabelColumn>;
+
+fn row(races: &Races, label: &'static str) -> Result<usize, Error> {
+    races.label_index(label).ok_or(Error::MissingLine(label))
 }
 
 /// Total distance follows the function:
@@ -28,15 +39,9 @@ pub enum Error {
 /// x = (-(t) +- sqrt((-t)² - 4(-1)(-m))) / 2(-1)
 /// x = (-t +- sqrt(t² - 4m)) / -2
 /// ```
-pub fn task1(mut input: Linewise<String>) -> Result<u64, Error> {
-    let times = input.next()
-        .ok_or(Error::MissingLine("Time"))?
-        .unwrap();
-    let times = times[5..].split_ascii_whitespace().map(u64::from_str);
-    let distances = input.next()
-        .ok_or(Error::MissingLine("Distance"))?
-        .unwrap();
-    let distances = distances[9..].split_ascii_whitespace().map(u64::from_str);
+pub fn task1(races: Races) -> Result<u64, Error> {
+    let times = races.parse_row::<u64>(row(&races, "Time:")?);
+    let distances = races.parse_row::<u64>(row(&races, "Distance:")?);
 
     let mut result = 1;
 
@@ -64,18 +69,13 @@ fn find_limits(time: u64, min_dist: u64) -> (u64, u64) {
     (min, max)
 }
 
-pub fn task2(mut input: Linewise<String>) -> Result<u64, Error> {
-    let time = input.next()
-        .ok_or(Error::MissingLine("Time"))?
-        .unwrap()
-        .replace(char::is_whitespace, "");
-    let time = u64::from_str(&time[5..])?;
-
-    let distance = input.next()
-        .ok_or(Error::MissingLine("Distance"))?
-        .unwrap()
-        .replace(char::is_whitespace, "");
-    let distance = u64::from_str(&distance[9..])?;
+pub fn task2(races: Races) -> Result<u64, Error> {
+    // the kerning is wrong, the digits of each row form a single number
+    let time = races.row(row(&races, "Time:")?).flatten().collect::<String>();
+    let time = u64::from_str(&time)?;
+
+    let distance = races.row(row(&races, "Distance:")?).flatten().collect::<String>();
+    let distance = u64::from_str(&distance)?;
 
     let t = find_limits(time, distance);
     let result = t.1 - t.0 + 1;
@@ -1,3 +1,5 @@
+// aoc: title = "Camel Cards"
+
 use std::cmp::Ordering;
 use std::num::ParseIntError;
 use std::str::FromStr;
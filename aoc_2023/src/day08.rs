@@ -1,3 +1,5 @@
+// aoc: title = "Haunted Wasteland", tags = [NumberTheory]
+
 use std::convert::Infallible;
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
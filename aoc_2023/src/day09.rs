@@ -1,11 +1,13 @@
+// aoc: title = "Mirage Maintenance"
+
 use std::num::ParseIntError;
-use common::input::{Linewise, SpaceSeparated};
+use common::input::{InputError, Linewise, SpaceSeparated};
 use common::iter_ext::TryIterator;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseInt(#[from] ParseIntError),
+    ParseInt(#[from] InputError<ParseIntError>),
 }
 
 pub fn task1(input: Linewise<SpaceSeparated<i32>>) -> Result<i32, Error> {
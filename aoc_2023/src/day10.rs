@@ -1,8 +1,10 @@
+// aoc: title = "Pipe Maze", tags = [Grid]
+
 use std::io::BufRead;
 use bitflags::bitflags;
 use common::geometry_2d::{Direction, Point};
-use common::input::{Charwise, Input, Linewise};
-use common::iter_ext::TryIterator;
+use common::grid::{Grid, GridError};
+use common::input::Input;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -33,45 +35,32 @@ impl Connections {
 
 #[derive(Debug)]
 pub struct Map {
-    tiles: Vec<Vec<Connections>>,
+    tiles: Grid<Connections>,
 }
 
 impl Map {
     fn get(&self, p: Point) -> Connections {
-        self.tiles[p.y as usize][p.x as usize]
+        self.tiles[p]
     }
     fn find_start(&self) -> Point {
-        for y in 0..self.tiles.len() {
-            let line = &self.tiles[y];
-            for x in 0..line.len() {
-                if self.tiles[y][x] == Connections::START {
-                    return Point { x: x as u32, y: y as u32 };
-                }
-            }
-        }
-        panic!("Failed to find start point")
+        self.tiles.position(|&c| c == Connections::START).expect("Failed to find start point")
     }
 }
 
 impl Input<'_> for Map {
-    type Error = Error;
+    type Error = GridError<Error>;
     fn parse<R: BufRead>(read: R) -> Result<Self, Self::Error> {
-        let tiles = Linewise::<Charwise<char>>::parse(read).unwrap()
-            .map(|line| {
-                line.unwrap().map(|c| {
-                    match c.unwrap() {
-                        '|' => Ok(Connections::TOP | Connections::BOTTOM),
-                        '-' => Ok(Connections::LEFT | Connections::RIGHT),
-                        'L' => Ok(Connections::TOP | Connections::RIGHT),
-                        'J' => Ok(Connections::LEFT | Connections::TOP),
-                        '7' => Ok(Connections::LEFT | Connections::BOTTOM),
-                        'F' => Ok(Connections::BOTTOM | Connections::RIGHT),
-                        '.' => Ok(Connections::empty()),
-                        'S' => Ok(Connections::START),
-                        c => Err(Error::InvalidMapChar(c))
-                    }
-                }).try_collect2::<Vec<_>>()
-            }).try_collect2::<Vec<_>>()?;
+        let tiles = Grid::parse_with(read, |_, c| match c {
+            '|' => Ok(Connections::TOP | Connections::BOTTOM),
+            '-' => Ok(Connections::LEFT | Connections::RIGHT),
+            'L' => Ok(Connections::TOP | Connections::RIGHT),
+            'J' => Ok(Connections::LEFT | Connections::TOP),
+            '7' => Ok(Connections::LEFT | Connections::BOTTOM),
+            'F' => Ok(Connections::BOTTOM | Connections::RIGHT),
+            '.' => Ok(Connections::empty()),
+            'S' => Ok(Connections::START),
+            c => Err(Error::InvalidMapChar(c))
+        })?;
         Ok(Self { tiles })
     }
 }
@@ -1,3 +1,5 @@
+// aoc: title = "Cosmic Expansion", tags = [Grid]
+
 use common::geometry_2d::Point;
 use common::input::Linewise;
 
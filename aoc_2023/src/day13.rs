@@ -1,3 +1,5 @@
+// aoc: title = "Point of Incidence", tags = [Grid]
+
 use common::bit_set::BitSet;
 use common::debug::BinDebug;
 use std::fmt::{Debug, Formatter};
@@ -1,5 +1,7 @@
+// aoc: title = "Historian Hysteria"
+
 use std::collections::hash_map::Entry;
-use common::input::Linewise;
+use common::input::{InputError, Linewise};
 use std::num::ParseIntError;
 use std::str::FromStr;
 use ahash::{HashMap, HashMapExt};
@@ -7,7 +9,7 @@ use ahash::{HashMap, HashMapExt};
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseInt(#[from] ParseIntError),
+    ParseInt(#[from] InputError<ParseIntError>),
 }
 
 #[derive(Debug, Copy, Clone)]
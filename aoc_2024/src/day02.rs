@@ -1,4 +1,6 @@
-use common::input::Linewise;
+// aoc: title = "Red-Nosed Reports"
+
+use common::input::{InputError, Linewise};
 use common::iter_ext::TryIterator;
 use std::cmp::Ordering;
 use std::num::ParseIntError;
@@ -8,7 +10,7 @@ use std::str::FromStr;
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseInt(#[from] ParseIntError),
+    ParseInt(#[from] InputError<ParseIntError>),
 }
 
 pub struct Report(Vec<u32>);
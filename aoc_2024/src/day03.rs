@@ -1,3 +1,5 @@
+// aoc: title = "Mull It Over", tags = [Parsing]
+
 use nom::branch::alt;
 use nom::character::complete::anychar;
 use nom::combinator::map;
@@ -1,12 +1,12 @@
-use common::input::Linewise;
+// aoc: title = "Ceres Search", tags = [Grid]
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {}
 
-pub fn task1(input: Linewise<String>) -> Result<i32, Error> {
+pub fn task1(input: &[u8]) -> Result<i32, Error> {
     let mut chars = vec![];
-    for line in input {
-        chars.push(line.unwrap().into_bytes())
+    for line in input.split(|&b| b == b'\n') {
+        chars.push(line.trim_ascii())
     }
 
     let height = chars.len();
@@ -21,7 +21,7 @@ pub fn task1(input: Linewise<String>) -> Result<i32, Error> {
     Ok(total)
 }
 
-fn count_xmas_lines(counter: &mut i32, chars: &Vec<Vec<u8>>, x: usize, y: usize) {
+fn count_xmas_lines(counter: &mut i32, chars: &[&[u8]], x: usize, y: usize) {
     is_xmas_line(counter, chars, x, y, -1, 1);
     is_xmas_line(counter, chars, x, y, 0, 1);
     is_xmas_line(counter, chars, x, y, 1, 1);
@@ -35,7 +35,7 @@ fn count_xmas_lines(counter: &mut i32, chars: &Vec<Vec<u8>>, x: usize, y: usize)
 
 fn is_xmas_line(
     counter: &mut i32,
-    chars: &Vec<Vec<u8>>,
+    chars: &[&[u8]],
     x: usize,
     y: usize,
     x_step: isize,
@@ -63,10 +63,10 @@ fn is_xmas_line(
     Some(())
 }
 
-pub fn task2(input: Linewise<String>) -> Result<i32, Error> {
+pub fn task2(input: &[u8]) -> Result<i32, Error> {
     let mut chars = vec![];
-    for line in input {
-        chars.push(line.unwrap().into_bytes())
+    for line in input.split(|&b| b == b'\n') {
+        chars.push(line.trim_ascii())
     }
 
     let height = chars.len();
@@ -83,7 +83,7 @@ pub fn task2(input: Linewise<String>) -> Result<i32, Error> {
     Ok(total)
 }
 
-fn is_xmas_cross(chars: &Vec<Vec<u8>>, x: usize, y: usize) -> bool {
+fn is_xmas_cross(chars: &[&[u8]], x: usize, y: usize) -> bool {
     if chars[y][x] != b'A' {
         return false;
     }
@@ -123,15 +123,15 @@ MXMXAXMASX";
 
     #[test]
     fn test_task1() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task1(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task1(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 18);
     }
     #[test]
     fn test_task2() {
-        let buf = std::io::BufReader::new(INPUT);
-        let result = task2(Input::parse(buf).unwrap());
+        let buf = INPUT;
+        let result = task2(Input::parse_bytes(buf).unwrap());
         let val = result.unwrap();
         assert_eq!(val, 9);
     }
@@ -1,6 +1,8 @@
+// aoc: title = "Print Queue"
+
 use ahash::{HashMap, HashMapExt};
 use common::bit_set::BitSet;
-use common::input::{LineSeparated, Linewise};
+use common::input::{InputError, LineSeparated, Linewise};
 use common::iter_ext::TryIterator;
 use std::num::ParseIntError;
 use std::ops::{Deref, DerefMut};
@@ -12,8 +14,12 @@ pub enum Error {
     MissingDelimiter,
     #[error(transparent)]
     ParseInt(#[from] ParseIntError),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 pub struct Rule {
     before: u8,
     after: u8,
@@ -55,7 +61,7 @@ impl FromStr for Update {
 }
 
 pub fn task1<'a>(
-    input: LineSeparated<'a, Linewise<'static, Rule>, Linewise<'a, Update>>,
+    input: LineSeparated<'a, Linewise<'a, Rule>, Linewise<'a, Update>>,
 ) -> Result<u32, Error> {
     let (rule_source, update_source) = input.into_inner();
 
@@ -100,7 +106,7 @@ fn is_safe(rules: &mut [Vec<u8>; 100], printed: &mut BitSet, update: &Update) ->
 }
 
 pub fn task2<'a>(
-    input: LineSeparated<'a, Linewise<'static, Rule>, Linewise<'a, Update>>,
+    input: LineSeparated<'a, Linewise<'a, Rule>, Linewise<'a, Update>>,
 ) -> Result<u32, Error> {
     let (rule_source, update_source) = input.into_inner();
 
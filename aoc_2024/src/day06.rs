@@ -1,65 +1,41 @@
+// aoc: title = "Guard Gallivant", tags = [Grid, Simulation]
+
 use ahash::{HashSet, HashSetExt};
-use common::bit_set::BitSet;
+use common::geometry_2d::Point;
+use common::grid::{Grid, GridError};
 use common::input::Input;
+use common::viz::{Frame, NoViz, Observer, Recorder, Visualize};
 use nalgebra::{point, vector, Point2, SVector};
+use std::convert::Infallible;
 use std::io::BufRead;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Missing guard token '^' in input")]
     MissingGuard,
+    #[error(transparent)]
+    Grid(#[from] GridError<Infallible>),
 }
 
 pub struct Map {
-    obstacles: BitSet,
-    width: usize,
-    height: usize,
+    obstacles: Grid<bool>,
     guard_start: Point2<i32>,
 }
 
 impl Input<'_> for Map {
     type Error = Error;
 
-    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
-        let mut obstacles = Vec::new();
-        let mut width = 0;
-        let mut y = 0;
+    fn parse<R: BufRead>(read: R) -> Result<Self, Self::Error> {
         let mut guard_start = None;
-        let mut line = String::new();
-        loop {
-            line.clear();
-            match read.read_line(&mut line) {
-                Ok(0) | Err(_) => break,
-                _ => {}
-            };
-
-            let line = line.trim();
-            for (x, c) in line.bytes().enumerate() {
-                if c == b'#' {
-                    obstacles.push((x, y));
-                } else if c == b'^' {
-                    guard_start = Some(point!(x as i32, y));
-                }
+        let obstacles = Grid::parse_with(read, |p, c| {
+            if c == '^' {
+                guard_start = Some(point!(p.x as i32, p.y as i32));
             }
-
-            width = line.len();
-            y += 1;
-        }
-
-        let height = y as usize;
-        let mut obstacle_bits = BitSet::new(width * height);
-        for (x, y) in obstacles {
-            let index = (y as usize * width) + x;
-            obstacle_bits.set(index)
-        }
+            Ok(c == '#')
+        })?;
 
         let guard_start = guard_start.ok_or(Error::MissingGuard)?;
-        Ok(Self {
-            obstacles: obstacle_bits,
-            width,
-            height,
-            guard_start,
-        })
+        Ok(Self { obstacles, guard_start })
     }
 }
 
@@ -90,10 +66,46 @@ impl Direction {
     }
 }
 
+/// The guard walking the map along with all states visited so far.
+struct Patrol<'a> {
+    map: &'a Map,
+    visited: &'a HashSet<(Point2<i32>, Direction)>,
+    position: Point2<i32>,
+    direction: Direction,
+}
+
+impl Visualize for Patrol<'_> {
+    fn frame(&self) -> Frame {
+        let obstacles = &self.map.obstacles;
+        let mut frame = Frame::from_fn(obstacles.width(), obstacles.height(), |x, y| {
+            if obstacles[Point::new(x as u32, y as u32)] { '#' } else { '.' }
+        });
+        for (pos, _) in self.visited {
+            frame.set(pos.x as usize, pos.y as usize, 'X');
+        }
+        let guard = match self.direction {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        frame.set(self.position.x as usize, self.position.y as usize, guard);
+        frame
+    }
+}
+
 pub fn task1(input: Map) -> Result<usize, Error> {
+    task1_core(input, &mut NoViz)
+}
+
+pub fn task1_viz(input: Map, recorder: &mut Recorder) -> Result<usize, Error> {
+    task1_core(input, recorder)
+}
+
+fn task1_core(input: Map, observer: &mut impl Observer) -> Result<usize, Error> {
     let mut states = HashSet::new();
 
-    let did_loop = get_path(&input, &mut states);
+    let did_loop = get_path(&input, &mut states, observer);
     assert_eq!(did_loop, false, "Found infinite loop in input");
 
     let positions = states
@@ -103,7 +115,7 @@ pub fn task1(input: Map) -> Result<usize, Error> {
     Ok(positions.len())
 }
 
-fn get_path(map: &Map, cache: &mut HashSet<(Point2<i32>, Direction)>) -> bool {
+fn get_path(map: &Map, cache: &mut HashSet<(Point2<i32>, Direction)>, observer: &mut impl Observer) -> bool {
     debug_assert!(cache.is_empty());
 
     let mut position = map.guard_start;
@@ -117,6 +129,10 @@ fn get_path(map: &Map, cache: &mut HashSet<(Point2<i32>, Direction)>) -> bool {
 
                 position = end;
                 direction = direction.next();
+                observer.frame(|| {
+                    let patrol = Patrol { map, visited: cache, position, direction };
+                    patrol.frame().with_status(format!("visited states: {}", cache.len()))
+                });
             }
             Err(end) => {
                 return insert_ray_points(cache, position, end, direction);
@@ -160,13 +176,10 @@ fn cast_ray(
             return Err(pos);
         }
 
-        if next.x as usize >= map.width || next.y as usize >= map.height {
-            return Err(pos);
-        }
-
-        let index = next.y as usize * map.width + next.x as usize;
-        if map.obstacles.get(index) {
-            return Ok(pos);
+        match map.obstacles.get(Point::new(next.x as u32, next.y as u32)) {
+            None => return Err(pos),
+            Some(true) => return Ok(pos),
+            Some(false) => {}
         }
 
         pos = next;
@@ -176,7 +189,7 @@ fn cast_ray(
 pub fn task2(mut map: Map) -> Result<i32, Error> {
     let mut init_path = HashSet::new();
 
-    let init_loop = get_path(&map, &mut init_path);
+    let init_loop = get_path(&map, &mut init_path, &mut NoViz);
     assert_eq!(init_loop, false, "Found infinite loop in initial path");
 
     let positions = init_path
@@ -187,11 +200,11 @@ pub fn task2(mut map: Map) -> Result<i32, Error> {
     let mut loops = 0;
     let mut loop_cache = HashSet::new();
     for block_point in positions {
-        let block_index = block_point.y as usize * map.width + block_point.x as usize;
-        map.obstacles.set(block_index);
+        let block = Point::new(block_point.x as u32, block_point.y as u32);
+        map.obstacles[block] = true;
         loop_cache.clear();
-        let did_loop = get_path(&map, &mut loop_cache);
-        map.obstacles.unset(block_index);
+        let did_loop = get_path(&map, &mut loop_cache, &mut NoViz);
+        map.obstacles[block] = false;
 
         if did_loop {
             loops += 1;
@@ -1,4 +1,6 @@
-use common::input::Linewise;
+// aoc: title = "Bridge Repair"
+
+use common::input::{InputError, Linewise};
 use common::iter_ext::TryIterator;
 use std::num::ParseIntError;
 use std::str::FromStr;
@@ -9,8 +11,12 @@ pub enum Error {
     MissingSeparator,
     #[error(transparent)]
     ParseInt(#[from] ParseIntError),
+    #[error(transparent)]
+    Input(Box<InputError<Error>>),
 }
 
+common::box_input_error!(Error);
+
 pub struct Equation {
     result: u64,
     values: Vec<u64>,
@@ -123,9 +129,41 @@ pub fn task2(input: Linewise<Equation>) -> Result<u64, Error> {
     Ok(total)
 }
 
+/// Solves task 2 by undoing the operators from the last value backwards,
+/// which prunes most branches early instead of trying every permutation.
+pub fn task2_backwards(input: Linewise<Equation>) -> Result<u64, Error> {
+    let mut total = 0;
+    for equation in input {
+        let equation = equation?;
+        if is_solvable_backwards(equation.result, &equation.values) {
+            total += equation.result;
+        }
+    }
+    Ok(total)
+}
+
+fn is_solvable_backwards(target: u64, values: &[u64]) -> bool {
+    let Some((&last, rest)) = values.split_last() else {
+        return false;
+    };
+    if rest.is_empty() {
+        return target == last;
+    }
+
+    if target >= last && is_solvable_backwards(target - last, rest) {
+        return true;
+    }
+    if last != 0 && target % last == 0 && is_solvable_backwards(target / last, rest) {
+        return true;
+    }
+    let magnitude = 10_u64.pow(last.checked_ilog10().unwrap_or(0) + 1);
+    target % magnitude == last && is_solvable_backwards(target / magnitude, rest)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::fuzz::{self, Case, Rng};
     use common::input::Input;
 
     const INPUT: &[u8] = b"\
@@ -153,4 +191,73 @@ mod tests {
         let val = result.unwrap();
         assert_eq!(val, 11387);
     }
+    #[test]
+    fn test_task2_backwards() {
+        let buf = std::io::BufReader::new(INPUT);
+        let result = task2_backwards(Input::parse(buf).unwrap());
+        let val = result.unwrap();
+        assert_eq!(val, 11387);
+    }
+
+    /// Random equations of which about half can be solved with the three operators.
+    #[derive(Debug, Clone)]
+    struct Equations(Vec<(u64, Vec<u64>)>);
+
+    impl Case for Equations {
+        fn generate(rng: &mut Rng) -> Self {
+            let lines = (0..rng.range(1, 10))
+                .map(|_| {
+                    let values = (0..rng.range(2, 6)).map(|_| rng.range(1, 99)).collect::<Vec<_>>();
+                    let result = match rng.bool() {
+                        true => values[1..].iter().fold(values[0], |acc, &v| match rng.range(0, 2) {
+                            0 => acc + v,
+                            1 => acc * v,
+                            _ => concat(acc, v),
+                        }),
+                        false => rng.range(0, 100_000),
+                    };
+                    (result, values)
+                })
+                .collect();
+            Self(lines)
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            let shrink_line = |(result, values): &(u64, Vec<u64>)| {
+                let mut out = fuzz::shrink_u64(*result, 0).into_iter().map(|r| (r, values.clone())).collect::<Vec<_>>();
+                let shorter = fuzz::shrink_vec(values, |v| fuzz::shrink_u64(*v, 1));
+                out.extend(shorter.into_iter().filter(|v| v.len() >= 2).map(|v| (*result, v)));
+                out
+            };
+            fuzz::shrink_vec(&self.0, shrink_line).into_iter().filter(|l| !l.is_empty()).map(Self).collect()
+        }
+
+        fn render(&self) -> String {
+            let lines = self.0.iter().map(|(result, values)| {
+                let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+                format!("{result}: {}", values.join(" "))
+            });
+            lines.collect::<Vec<_>>().join("\n")
+        }
+    }
+
+    #[test]
+    fn fuzz_task2_alternatives() {
+        let task = crate::YEAR.day("day07").unwrap().task("task2").unwrap();
+        fuzz::check::<Equations>(200, fuzz::agree(task));
+    }
+
+    #[test]
+    fn fuzz_task1_below_task2() {
+        let day = crate::YEAR.day("day07").unwrap();
+        let params = Default::default();
+        fuzz::check::<Equations>(200, |input| {
+            let task1 = day.task("task1").unwrap().run(input.as_bytes(), &params).map_err(|e| e.to_string())?;
+            let task2 = day.task("task2").unwrap().run(input.as_bytes(), &params).map_err(|e| e.to_string())?;
+            match task1.parse::<u64>().unwrap() <= task2.parse::<u64>().unwrap() {
+                true => Ok(()),
+                false => Err(format!("task1 returned {task1} which is more than {task2} of task2")),
+            }
+        });
+    }
 }
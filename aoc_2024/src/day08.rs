@@ -1,6 +1,10 @@
+// aoc: title = "Resonant Collinearity", tags = [Grid]
+
 use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use common::grid::{Grid, GridError};
 use common::input::Input;
 use nalgebra::{point, Point2};
+use std::convert::Infallible;
 use std::io::BufRead;
 
 #[derive(Debug, thiserror::Error)]
@@ -9,42 +13,25 @@ pub enum Error {}
 pub struct Map {
     width: usize,
     height: usize,
-    antennas: HashMap<u8, Vec<Point2<i32>>>,
+    antennas: HashMap<char, Vec<Point2<i32>>>,
 }
 
 impl Input<'_> for Map {
-    type Error = Error;
-
-    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
-        let mut antennas = HashMap::<u8, Vec<Point2<i32>>>::new();
-        let mut y = 0;
-        let mut line = String::new();
-        let mut width = 0;
-        loop {
-            line.clear();
-            if read.read_line(&mut line).unwrap_or(0) == 0 {
-                break;
+    type Error = GridError<Infallible>;
+
+    fn parse<R: BufRead>(read: R) -> Result<Self, Self::Error> {
+        let mut antennas = HashMap::<char, Vec<Point2<i32>>>::new();
+        // only the size of the map is needed besides the antennas
+        let grid = Grid::parse_with(read, |p, c| {
+            if c != '.' {
+                antennas.entry(c).or_default().push(point![p.x as i32, p.y as i32]);
             }
-
-            let line = line.trim();
-            width = line.len();
-
-            for (x, c) in line.as_bytes().iter().cloned().enumerate() {
-                if c == b'.' {
-                    continue;
-                }
-
-                let list = antennas.entry(c).or_default();
-                list.push(point![x as i32, y])
-            }
-
-            y += 1;
-        }
-        let height = y as usize;
+            Ok(())
+        })?;
 
         Ok(Self {
-            width,
-            height,
+            width: grid.width(),
+            height: grid.height(),
             antennas,
         })
     }
@@ -137,7 +124,7 @@ fn print_result(map: &Map, antinodes: &HashSet<Point2<i32>>) {
     let mut lines = Vec::with_capacity(map.height);
     for y in 0..map.height {
         let y = y as i32;
-        let mut line = String::new();
+        let mut line = Vec::with_capacity(map.width);
         for x in 0..map.width {
             let x = x as i32;
             let c = if antinodes.contains(&point![x, y]) {
@@ -152,13 +139,12 @@ fn print_result(map: &Map, antinodes: &HashSet<Point2<i32>>) {
 
     for (&key, points) in &map.antennas {
         for &point in points {
-            let row = unsafe { lines[point.y as usize].as_bytes_mut() };
-            row[point.x as usize] = key;
+            lines[point.y as usize][point.x as usize] = key;
         }
     }
 
     for line in lines {
-        println!("{line}")
+        println!("{}", line.into_iter().collect::<String>())
     }
 }
 
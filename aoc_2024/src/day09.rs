@@ -1,3 +1,5 @@
+// aoc: title = "Disk Fragmenter"
+
 use std::ops::Range;
 
 #[derive(Debug, thiserror::Error)]
@@ -1,3 +1,5 @@
+// aoc: title = "Plutonian Pebbles", tags = [Simulation]
+
 use ahash::{HashMap, HashMapExt};
 use common::iter_ext::TryIterator;
 use std::mem::swap;
@@ -1,13 +1,22 @@
+// aoc: title = "Restroom Redoubt", tags = [Grid, Simulation]
+
 use ahash::{HashSet, HashSetExt};
-use common::input::Linewise;
+use common::input::{InputError, IntsError, IntsN, Linewise};
 use common::iter_ext::TryIterator;
+use common::params::{ParamError, Params};
+use common::viz::{Frame, NoViz, Observer, Recorder, Visualize};
 use nalgebra::{point, vector, Point2, Vector2};
+use std::num::ParseIntError;
 use std::str::FromStr;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    ParseError(#[from] pattern_parse::ParseError),
+    ParseError(#[from] InputError<IntsError<ParseIntError>>),
+    #[error(transparent)]
+    Param(#[from] ParamError),
+    #[error("the floor needs a width and height of at least 1")]
+    EmptyFloor,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -17,12 +26,13 @@ pub struct Robot {
 }
 
 impl FromStr for Robot {
-    type Err = pattern_parse::ParseError;
+    type Err = IntsError<ParseIntError>;
 
+    /// `p=0,4 v=3,-3`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        pattern_parse::parse_fn!(parse, "p={usize},{usize} v={isize},{isize}");
-
-        let (px, py, vx, vy) = parse(s)?;
+        let (position, velocity) = s.split_once(' ').unwrap_or((s, ""));
+        let [px, py] = position.parse::<IntsN<usize, 2>>()?.into_inner();
+        let [vx, vy] = velocity.parse::<IntsN<isize, 2>>()?.into_inner();
         Ok(Self {
             position: Point2::new(px, py),
             velocity: Vector2::new(vx, vy),
@@ -30,27 +40,60 @@ impl FromStr for Robot {
     }
 }
 
-pub fn task1(input: Linewise<Robot>) -> Result<u32, Error> {
-    task1_core::<101, 103>(input)
+/// The floor of the bathroom with all robots on it.
+struct Floor<'a> {
+    width: usize,
+    height: usize,
+    robots: &'a [Robot],
+}
+
+impl<'a> Floor<'a> {
+    fn new(params: &Params, robots: &'a [Robot]) -> Result<Self, Error> {
+        match (params.get("width")?, params.get("height")?) {
+            (0, _) | (_, 0) => Err(Error::EmptyFloor),
+            (width, height) => Ok(Self { width, height, robots }),
+        }
+    }
+
+    fn step(&self, bot: &mut Robot) {
+        let x = (bot.position.x + self.width).wrapping_add_signed(bot.velocity.x);
+        let y = (bot.position.y + self.height).wrapping_add_signed(bot.velocity.y);
+        bot.position = point![x % self.width, y % self.height];
+    }
+}
+
+impl Visualize for Floor<'_> {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.width, self.height, ' ');
+        for bot in self.robots {
+            frame.set(bot.position.x, bot.position.y, '#');
+        }
+        frame
+    }
 }
-fn task1_core<const WIDTH: usize, const HEIGHT: usize>(
-    input: Linewise<Robot>,
-) -> Result<u32, Error> {
+
+// aoc: params = [width = 101, height = 103], example = [width = 11, height = 7]
+pub fn task1(input: Linewise<Robot>, params: &Params) -> Result<u32, Error> {
+    task1_core(input, params, &mut NoViz)
+}
+
+pub fn task1_viz(input: Linewise<Robot>, params: &Params, recorder: &mut Recorder) -> Result<u32, Error> {
+    task1_core(input, params, recorder)
+}
+
+fn task1_core(input: Linewise<Robot>, params: &Params, observer: &mut impl Observer) -> Result<u32, Error> {
     let mut robots = input.try_collect2::<Vec<_>>()?;
+    let floor = Floor::new(params, &[])?;
 
-    for _step in 0..100 {
+    for step in 0..100 {
         for bot in &mut robots {
-            let mut x = (bot.position.x + WIDTH).wrapping_add_signed(bot.velocity.x);
-            let mut y = (bot.position.y + HEIGHT).wrapping_add_signed(bot.velocity.y);
-            x %= WIDTH;
-            y %= HEIGHT;
-            bot.position = point![x, y];
+            floor.step(bot);
         }
-        print_map::<WIDTH, HEIGHT>(&robots);
+        observer.frame(|| Floor { robots: &robots, ..floor }.frame().with_status(format!("seconds: {}", step + 1)));
     }
 
-    let mid_x = WIDTH / 2;
-    let mid_y = HEIGHT / 2;
+    let mid_x = floor.width / 2;
+    let mid_y = floor.height / 2;
 
     let mut count_tr = 0;
     let mut count_tl = 0;
@@ -79,35 +122,33 @@ fn task1_core<const WIDTH: usize, const HEIGHT: usize>(
     Ok(count_tr * count_tl * count_br * count_bl)
 }
 
-pub fn task2(input: Linewise<Robot>) -> Result<u32, Error> {
-    task2_core::<101, 103>(input)
+// aoc: params = [width = 101, height = 103]
+pub fn task2(input: Linewise<Robot>, params: &Params) -> Result<u32, Error> {
+    task2_core(input, params, &mut NoViz)
 }
 
-fn task2_core<const WIDTH: usize, const HEIGHT: usize>(
-    input: Linewise<Robot>,
-) -> Result<u32, Error> {
+pub fn task2_viz(input: Linewise<Robot>, params: &Params, recorder: &mut Recorder) -> Result<u32, Error> {
+    task2_core(input, params, recorder)
+}
+
+fn task2_core(input: Linewise<Robot>, params: &Params, observer: &mut impl Observer) -> Result<u32, Error> {
     let mut robots = input.try_collect2::<Vec<_>>()?;
+    let floor = Floor::new(params, &[])?;
     let mut step = 0;
     let mut position_cache = HashSet::new();
 
     loop {
         step += 1;
         for bot in &mut robots {
-            let mut x = (bot.position.x + WIDTH).wrapping_add_signed(bot.velocity.x);
-            let mut y = (bot.position.y + HEIGHT).wrapping_add_signed(bot.velocity.y);
-            x %= WIDTH;
-            y %= HEIGHT;
-            bot.position = point![x, y];
+            floor.step(bot);
         }
+        observer.frame(|| Floor { robots: &robots, ..floor }.frame().with_status(format!("seconds: {step}")));
 
         position_cache.clear();
         position_cache.extend(robots.iter().map(|r| r.position));
         if has_contiguous(&position_cache, vector![1, 0], 6)
             && has_contiguous(&position_cache, vector![0, 1], 6)
         {
-            if cfg!(debug_assertions) {
-                print_map::<WIDTH, HEIGHT>(&robots);
-            }
             break;
         }
     }
@@ -129,29 +170,6 @@ fn has_contiguous(robots: &HashSet<Point2<usize>>, offset: Vector2<usize>, total
     false
 }
 
-fn print_map<const WIDTH: usize, const HEIGHT: usize>(bots: &Vec<Robot>) {
-    println!("======================================================================");
-
-    let mut positions = HashSet::with_capacity(bots.len());
-    for bot in bots {
-        positions.insert(bot.position);
-    }
-
-    let mut out = String::new();
-    for y in 0..HEIGHT {
-        for x in 0..WIDTH {
-            if positions.contains(&point![x, y]) {
-                out.push('#')
-            } else {
-                out.push(' ')
-            }
-        }
-        out.push('\n');
-    }
-
-    println!("{out}");
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,8 +192,19 @@ p=9,5 v=-3,-3";
     #[test]
     fn test_task1() {
         let buf = std::io::BufReader::new(INPUT);
-        let result = task1_core::<11, 7>(Input::parse(buf).unwrap());
+        let result = task1(Input::parse(buf).unwrap(), &crate::YEAR.day("day14").unwrap().task("task1").unwrap().example_params());
         let val = result.unwrap();
         assert_eq!(val, 12);
     }
+
+    #[test]
+    fn rejects_empty_floor() {
+        let task = crate::YEAR.day("day14").unwrap().task("task1").unwrap();
+        for param in ["width", "height"] {
+            let mut params = task.example_params();
+            params.set(param, "0").unwrap();
+            let result = task1(Input::parse(INPUT).unwrap(), &params);
+            assert!(matches!(result, Err(Error::EmptyFloor)));
+        }
+    }
 }
@@ -1,3 +1,5 @@
+// aoc: title = "Chronospatial Computer", tags = [Simulation]
+
 use common::input::Linewise;
 use std::str::FromStr;
 
@@ -1,5 +1,8 @@
+// aoc: title = "RAM Run", tags = [Grid, Pathfinding]
+
 use ahash::HashSet;
 use common::input::Input;
+use common::params::{ParamError, Params};
 use common::pathfinding::Algorithm;
 use nalgebra::{point, vector, Vector2};
 use std::hash::{Hash, Hasher};
@@ -11,6 +14,10 @@ pub enum Error {
     PatternParse(#[from] pattern_parse::ParseError),
     #[error(transparent)]
     NoPath(#[from] common::pathfinding::astar::Error),
+    #[error(transparent)]
+    Param(#[from] ParamError),
+    #[error("the memory space needs a size of at least 1")]
+    EmptySpace,
 }
 
 pattern_parse::parse_fn!(parse, "{u8},{u8}");
@@ -35,26 +42,38 @@ impl Input<'_> for Points {
     }
 }
 
+/// The memory space of `size` by `size` cells with the first `count` bytes fallen into it.
 #[derive(Debug)]
-pub struct Map<const SIZE: u8>(HashSet<Point>);
+pub struct Map {
+    size: u8,
+    blocked: HashSet<Point>,
+}
 
-impl<const SIZE: u8> Map<SIZE> {
-    fn from_input(points: &Points, count: usize) -> Self {
-        let point_set = points.0.iter().take(count).cloned().collect();
-        Self(point_set)
+/// The `size` parameter, which can't be 0 as the exit is at `size - 1`.
+fn size(params: &Params) -> Result<u8, Error> {
+    match params.get("size")? {
+        0 => Err(Error::EmptySpace),
+        size => Ok(size),
     }
 }
 
-impl<const SIZE: u8> common::pathfinding::World<'_> for Map<SIZE> {
+impl Map {
+    fn from_input(points: &Points, size: u8, count: usize) -> Self {
+        let blocked = points.0.iter().take(count).cloned().collect();
+        Self { size, blocked }
+    }
+}
+
+impl common::pathfinding::World<'_> for Map {
     type Index = Index;
     type Neighbors = std::vec::IntoIter<Index>;
 
     fn get_neighbors(&self, origin: &Self::Index) -> Self::Neighbors {
-        fn try_find_neighbor<const SIZE: u8>(origin: &Index, offset: Vector2<i8>) -> Option<Index> {
+        fn try_find_neighbor(size: u8, origin: &Index, offset: Vector2<i8>) -> Option<Index> {
             let x = origin.point.x.checked_add_signed(offset.x)?;
             let y = origin.point.y.checked_add_signed(offset.y)?;
 
-            if x >= SIZE || y >= SIZE {
+            if x >= size || y >= size {
                 return None;
             }
 
@@ -65,28 +84,28 @@ impl<const SIZE: u8> common::pathfinding::World<'_> for Map<SIZE> {
         }
 
         let mut neighbors = vec![];
-        neighbors.extend(try_find_neighbor::<SIZE>(origin, vector![-1, 0]));
-        neighbors.extend(try_find_neighbor::<SIZE>(origin, vector![1, 0]));
-        neighbors.extend(try_find_neighbor::<SIZE>(origin, vector![0, -1]));
-        neighbors.extend(try_find_neighbor::<SIZE>(origin, vector![0, 1]));
+        neighbors.extend(try_find_neighbor(self.size, origin, vector![-1, 0]));
+        neighbors.extend(try_find_neighbor(self.size, origin, vector![1, 0]));
+        neighbors.extend(try_find_neighbor(self.size, origin, vector![0, -1]));
+        neighbors.extend(try_find_neighbor(self.size, origin, vector![0, 1]));
         neighbors.into_iter()
     }
 }
 
-impl<const SIZE: u8> Map<SIZE> {
+impl Map {
     fn is_blocked(&self, index: &Index) -> bool {
-        self.0.contains(&index.point)
+        self.blocked.contains(&index.point)
     }
 }
 
 struct Agent;
 
-impl<const SIZE: u8> common::pathfinding::Agent<'_, Map<SIZE>> for Agent {
+impl common::pathfinding::Agent<'_, Map> for Agent {
     type Cost = u32;
 
     fn get_cost(
         &self,
-        world: &Map<SIZE>,
+        world: &Map,
         start: &Index,
         destination: &Index,
     ) -> Option<Self::Cost> {
@@ -124,21 +143,20 @@ impl PartialEq<Self> for Index {
 
 impl Eq for Index {}
 
-pub fn task1(input: Points) -> Result<usize, Error> {
-    task1_core(Map::<71>::from_input(&input, 1024))
-}
-
-pub fn task1_core<const SIZE: u8>(map: Map<SIZE>) -> Result<usize, Error> {
+// aoc: params = [size = 71, bytes = 1024], example = [size = 7, bytes = 12]
+pub fn task1(input: Points, params: &Params) -> Result<usize, Error> {
+    let size = size(params)?;
+    let map = Map::from_input(&input, size, params.get("bytes")?);
     let agent = Agent;
     let start = Index {
         point: point![0, 0],
         time: Some(0),
     };
     let end = Index {
-        point: point![SIZE - 1, SIZE - 1],
+        point: point![size - 1, size - 1],
         time: None,
     };
-    let path = common::pathfinding::astar::Algorithm::<Map<SIZE>, _, _>::new(|_, start, end| {
+    let path = common::pathfinding::astar::Algorithm::<Map, _, _>::new(|_, start, end| {
         let x_diff = start.point.x.abs_diff(end.point.x) as u32;
         let y_diff = start.point.y.abs_diff(end.point.y) as u32;
         x_diff + y_diff
@@ -148,18 +166,16 @@ pub fn task1_core<const SIZE: u8>(map: Map<SIZE>) -> Result<usize, Error> {
     Ok(path.len() - 1)
 }
 
-pub fn task2(input: Points) -> Result<String, Error> {
-    task2_core::<71>(input)
-}
-
-pub fn task2_core<const SIZE: u8>(input: Points) -> Result<String, Error> {
+// aoc: slow, params = [size = 71], example = [size = 7]
+pub fn task2(input: Points, params: &Params) -> Result<String, Error> {
+    let size = size(params)?;
     let agent = Agent;
     let start = Index {
         point: point![0, 0],
         time: Some(0),
     };
     let end = Index {
-        point: point![SIZE - 1, SIZE - 1],
+        point: point![size - 1, size - 1],
         time: None,
     };
 
@@ -168,10 +184,10 @@ pub fn task2_core<const SIZE: u8>(input: Points) -> Result<String, Error> {
     let mut min_blocker = high; // Store the minimum number of points found
     while low <= high {
         let cursor = (low + high) / 2;
-        let map = Map::from_input(&input, cursor);
+        let map = Map::from_input(&input, size, cursor);
 
         let path =
-            common::pathfinding::astar::Algorithm::<Map<SIZE>, _, _>::new(|_, start, end| {
+            common::pathfinding::astar::Algorithm::<Map, _, _>::new(|_, start, end| {
                 let x_diff = start.point.x.abs_diff(end.point.x) as u32;
                 let y_diff = start.point.y.abs_diff(end.point.y) as u32;
                 x_diff + y_diff
@@ -232,16 +248,25 @@ mod tests {
     #[test]
     fn test_task1() {
         let buf = std::io::BufReader::new(INPUT);
-        let points = Points::parse(buf).unwrap();
-        let map = Map::<7>::from_input(&points, 12);
-        let result = task1_core(map);
+        let task = crate::YEAR.day("day18").unwrap().task("task1").unwrap();
+        let result = task1(Points::parse(buf).unwrap(), &task.example_params());
         let val = result.unwrap();
         assert_eq!(val, 22);
     }
+
+    #[test]
+    fn rejects_empty_space() {
+        let task = crate::YEAR.day("day18").unwrap().task("task1").unwrap();
+        let mut params = task.example_params();
+        params.set("size", "0").unwrap();
+        let result = task1(Points::parse(INPUT).unwrap(), &params);
+        assert!(matches!(result, Err(Error::EmptySpace)));
+    }
     #[test]
     fn test_task2() {
         let buf = std::io::BufReader::new(INPUT);
-        let result = task2_core::<7>(Input::parse(buf).unwrap());
+        let task = crate::YEAR.day("day18").unwrap().task("task2").unwrap();
+        let result = task2(Input::parse(buf).unwrap(), &task.example_params());
         let val = result.unwrap();
         assert_eq!(val.as_str(), "6,1");
     }
@@ -1,3 +1,5 @@
+// aoc: title = "Linen Layout", tags = [Parsing]
+
 use common::input::{LineSeparated, Linewise};
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,13 @@
+use std::process::Command;
+
+fn main() {
+    // plugins have to be built by the same compiler, see `plugin::ABI_VERSION`
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_owned())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={version}");
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,77 @@
+//! Derive macros re-exported by the `common` crate.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{punctuated::Punctuated, LitByte, LitChar, Token};
+
+fn derive_from_char_core(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(name, "FromChar can only be derived for enums"));
+    };
+
+    let mut char_arms = Vec::new();
+    let mut byte_arms = Vec::new();
+    let mut ascii = true;
+    for variant in &data.variants {
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(syn::Error::new_spanned(variant, "FromChar can only be derived for unit variants"));
+        }
+        let chars = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("char"))
+            .ok_or_else(|| syn::Error::new_spanned(variant, "missing #[char('.')] attribute"))?
+            .parse_args_with(Punctuated::<LitChar, Token!(,)>::parse_separated_nonempty)?;
+
+        ascii &= chars.iter().all(|c| c.value().is_ascii());
+        let bytes = chars.iter().map(|c| LitByte::new(c.value() as u8, c.span()));
+        let chars = chars.iter();
+        let ident = &variant.ident;
+        char_arms.push(quote!(#( #chars )|* => Ok(Self::#ident),));
+        byte_arms.push(quote!(#( #bytes )|* => Ok(Self::#ident),));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let from_byte = ascii.then(|| {
+        quote!(
+            impl #impl_generics ::common::input::FromByte for #name #ty_generics #where_clause {
+                type Err = ::common::input::UnexpectedChar;
+
+                fn from_byte(b: u8) -> Result<Self, Self::Err> {
+                    match b {
+                        #( #byte_arms )*
+                        b => Err(::common::input::UnexpectedChar(b as char)),
+                    }
+                }
+            }
+        )
+    });
+
+    Ok(quote!(
+        impl #impl_generics ::common::input::FromChar for #name #ty_generics #where_clause {
+            type Err = ::common::input::UnexpectedChar;
+
+            fn from_char(c: char) -> Result<Self, Self::Err> {
+                match c {
+                    #( #char_arms )*
+                    c => Err(::common::input::UnexpectedChar(c)),
+                }
+            }
+        }
+
+        #from_byte
+    ))
+}
+
+/// Derives `FromChar` for an enum of unit variants marked with the characters they are parsed from,
+/// like `#[char('#')]` or `#[char('.', 'S')]`.
+///
+/// `FromByte` is derived as well if all characters are ASCII.
+#[proc_macro_derive(FromChar, attributes(char))]
+pub fn derive_from_char(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(stream as syn::DeriveInput);
+    derive_from_char_core(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,322 @@
+//! Build script helpers generating the registries of days and years from the source tree.
+//!
+//! A year crate calls [`year`] from its `build.rs` and includes the generated `days.rs`:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/days.rs"));
+//! ```
+//!
+//! Every `src/dayNN.rs` becomes a day and every `pub fn taskN` at the start of a line inside it a task.
+//! A `pub fn taskN_viz` is registered as the visualization of `taskN`,
+//! any other `pub fn taskN_suffix` as an alternative implementation of it
+//! and a `pub fn parse` as the parse step shared by all tasks of the day.
+//! If present `docs/dayNN.md` is used as the documentation of the day module.
+//!
+//! Metadata is given in `// aoc: ...` comments using the syntax of [`decl_year!`](crate::decl_year).
+//! A comment directly above a task (ignoring doc comments and attributes) belongs to that task,
+//! all other ones belong to the day:
+//!
+//! ```ignore
+//! // aoc: title = "Regolith Reservoir", tags = [Grid, Simulation]
+//!
+//! // aoc: slow
+//! pub fn task2(map: Map) -> Result<u64, Error> { ... }
+//! ```
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+const ANNOTATION: &str = "// aoc:";
+
+#[derive(Debug)]
+struct DayFile {
+    name: String,
+    meta: Vec<String>,
+    tasks: Vec<TaskFn>,
+    doc: Option<PathBuf>,
+    path: Option<PathBuf>,
+    parse: bool,
+}
+
+#[derive(Debug)]
+struct TaskFn {
+    number: u32,
+    meta: Vec<String>,
+    viz: bool,
+    alternatives: Vec<String>,
+}
+
+/// Returns the number of `line` if it declares `pub fn taskN` (`suffix` being empty) or `pub fn taskN{suffix}`.
+/// Like [`is_parse_fn`] only functions at the top level of the module, starting in the first column, count.
+fn task_number(line: &str, suffix: &str) -> Option<u32> {
+    let rest = line.strip_prefix("pub fn task")?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let number = rest[..digits].parse().ok()?;
+    let rest = rest[digits..].strip_prefix(suffix)?;
+    rest.starts_with(['(', '<']).then_some(number)
+}
+
+/// Returns the number and suffix of `line` if it declares `pub fn taskN_suffix`.
+fn task_variant(line: &str) -> Option<(u32, &str)> {
+    let rest = line.strip_prefix("pub fn task")?;
+    let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+    let number = rest[..digits].parse().ok()?;
+    let rest = rest[digits..].strip_prefix('_')?;
+    let suffix = &rest[..rest.find(['(', '<'])?];
+    let valid = !suffix.is_empty() && suffix.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+    valid.then_some((number, suffix))
+}
+
+/// Whether the line declares the shared parse step `pub fn parse` at the top level of the module.
+fn is_parse_fn(line: &str) -> bool {
+    line.strip_prefix("pub fn parse")
+        .is_some_and(|rest| rest.starts_with(['(', '<']))
+}
+
+/// Whether the line can sit between an annotation and the function it belongs to.
+fn is_transparent(line: &str) -> bool {
+    let line = line.trim_start();
+    line.starts_with("//") || line.starts_with("#[")
+}
+
+fn parse_day(name: String, source: &str) -> DayFile {
+    let lines = source.trim_start_matches('\u{feff}').lines().collect::<Vec<_>>();
+    let mut day = DayFile { name, meta: Vec::new(), tasks: Vec::new(), doc: None, path: None, parse: false };
+    let mut task_meta = Vec::new();
+
+    for (i, line) in lines.iter().enumerate() {
+        if let Some(meta) = line.trim_start().strip_prefix(ANNOTATION) {
+            let meta = meta.trim().to_owned();
+            let target = lines[i + 1..].iter().find(|l| !is_transparent(l));
+            match target.and_then(|l| task_number(l, "")) {
+                Some(_) => task_meta.push(meta),
+                None => day.meta.push(meta),
+            }
+        } else if is_parse_fn(line) {
+            day.parse = true;
+        } else if let Some(number) = task_number(line, "") {
+            let meta = std::mem::take(&mut task_meta);
+            day.tasks.push(TaskFn { number, meta, viz: false, alternatives: Vec::new() });
+        }
+    }
+    for line in &lines {
+        let Some((number, suffix)) = task_variant(line) else { continue };
+        let Some(task) = day.tasks.iter_mut().find(|t| t.number == number) else { continue };
+        match suffix {
+            "viz" => task.viz = true,
+            _ => task.alternatives.push(format!("task{number}_{suffix}")),
+        }
+    }
+    day.tasks.sort_by_key(|t| t.number);
+    day
+}
+
+fn day_files(dir: &Path) -> std::io::Result<Vec<(String, PathBuf)>> {
+    let mut days = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else { continue };
+        let is_day = stem.strip_prefix("day").is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+        if is_day && path.extension().is_some_and(|e| e == "rs") {
+            days.push((stem.to_owned(), path));
+        }
+    }
+    days.sort();
+    Ok(days)
+}
+
+fn render_year(days: &[DayFile]) -> String {
+    let mut out = String::from("common::decl_year! {\n");
+    for day in days {
+        out.push_str("    ");
+        if let Some(doc) = &day.doc {
+            let _ = write!(out, "#[doc({:?})] ", doc.display().to_string());
+        }
+        if let Some(path) = &day.path {
+            let _ = write!(out, "#[path({:?})] ", path.display().to_string());
+        }
+        out.push_str(&day.name);
+        let mut meta = day.meta.clone();
+        if day.parse {
+            meta.push("parse = parse".to_owned());
+        }
+        if !meta.is_empty() {
+            let _ = write!(out, " [{}]", meta.join(", "));
+        }
+        out.push_str(" {");
+        for task in &day.tasks {
+            let mut meta = task.meta.clone();
+            if task.viz {
+                meta.push(format!("viz = task{}_viz", task.number));
+            }
+            if !task.alternatives.is_empty() {
+                meta.push(format!("impls = [{}]", task.alternatives.join(", ")));
+            }
+            let _ = write!(out, "task{}", task.number);
+            if !meta.is_empty() {
+                let _ = write!(out, " [{}]", meta.join(", "));
+            }
+            out.push(';');
+        }
+        out.push_str("}\n");
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn out_dir() -> PathBuf {
+    PathBuf::from(std::env::var_os("OUT_DIR").expect("must be called from a build script"))
+}
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").expect("must be called from a build script"))
+}
+
+/// Generates `$OUT_DIR/days.rs` declaring all days found in the `src` directory of the calling crate.
+pub fn year() -> std::io::Result<()> {
+    let root = manifest_dir();
+    let src = root.join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut days = Vec::new();
+    for (name, path) in day_files(&src)? {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let doc = root.join("docs").join(&name).with_extension("md");
+        let doc = doc.is_file().then_some(doc);
+        let source = std::fs::read_to_string(&path)?;
+        // the generated file lives in OUT_DIR so all paths have to be absolute
+        days.push(DayFile { doc, path: Some(path), ..parse_day(name, &source) });
+    }
+
+    std::fs::write(out_dir().join("days.rs"), render_year(&days))
+}
+
+/// The `aoc_YYYY` crates listed in the `[dependencies]` table of `manifest`, sorted and deduplicated.
+///
+/// Both `aoc_2024 = ...` entries (including dotted keys like `aoc_2024.path = ...`)
+/// and `[dependencies.aoc_2024]` tables are recognized.
+/// Other tables like `[dev-dependencies]`, `[features]` or target specific dependencies are ignored.
+fn year_dependencies(manifest: &str) -> Vec<&str> {
+    let is_year = |name: &str| name.strip_prefix("aoc_").is_some_and(|y| y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit()));
+    let mut in_dependencies = false;
+    let mut years = Vec::new();
+    for line in manifest.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.split(']').next().unwrap_or_default().trim();
+            in_dependencies = header == "dependencies";
+            if let Some(name) = header.strip_prefix("dependencies.").map(str::trim) {
+                years.extend(Some(name).filter(|name| is_year(name)));
+            }
+        } else if in_dependencies {
+            let name = line.split(['=', '.']).next().unwrap_or_default().trim();
+            if is_year(name) {
+                years.push(name);
+            }
+        }
+    }
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// Generates `$OUT_DIR/years.rs` re-exporting every `aoc_YYYY` dependency of the calling crate
+/// and listing them in a `YEARS` slice, see [`year_dependencies`] for the supported shape of the manifest.
+pub fn years() -> std::io::Result<()> {
+    let manifest = manifest_dir().join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let content = std::fs::read_to_string(manifest)?;
+    let years = year_dependencies(&content);
+
+    let mut out = String::new();
+    for year in &years {
+        let _ = writeln!(out, "pub use {year};");
+    }
+    out.push_str("\npub static YEARS: &[&common::Year] = &[\n");
+    for year in &years {
+        let _ = writeln!(out, "    &{year}::YEAR,");
+    }
+    out.push_str("];\n");
+
+    std::fs::write(out_dir().join("years.rs"), out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "\
+// aoc: title = \"Example\", tags = [Grid]
+
+pub fn parse(input: ()) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Doc comment
+// aoc: slow
+#[allow(unused)]
+pub fn task2(input: ()) -> Result<i32, Error> {
+    todo!()
+}
+
+pub fn task1<'a>(input: ()) -> Result<i32, Error> {
+    todo!()
+}
+
+pub fn task1_viz(input: (), recorder: &mut Recorder) -> Result<i32, Error> {
+    todo!()
+}
+
+pub fn task2_brute_force(input: ()) -> Result<i32, Error> {
+    todo!()
+}
+
+fn task3() {}
+";
+
+    #[test]
+    fn discovers_tasks() {
+        let day = parse_day("day01".to_owned(), SOURCE);
+        assert_eq!(
+            render_year(&[day]),
+            "common::decl_year! {\n    day01 [title = \"Example\", tags = [Grid], parse = parse] {task1 [viz = task1_viz];task2 [slow, impls = [task2_brute_force]];}\n}\n"
+        );
+    }
+
+    #[test]
+    fn task_numbers() {
+        assert_eq!(task_number("pub fn task12(input: ())", ""), Some(12));
+        assert_eq!(task_number("pub fn task1_viz(input: ())", ""), None);
+        assert_eq!(task_number("pub fn task1_viz(input: ())", "_viz"), Some(1));
+        assert_eq!(task_number("pub fn task(input: ())", ""), None);
+        assert_eq!(task_number("fn task1(input: ())", ""), None);
+        assert_eq!(task_number("    pub fn task1(input: ())", ""), None);
+        assert_eq!(task_variant("pub fn task2_brute_force<'a>(input: ())"), Some((2, "brute_force")));
+        assert_eq!(task_variant("pub fn task2(input: ())"), None);
+        assert_eq!(task_variant("pub fn task2_(input: ())"), None);
+        assert_eq!(task_variant("    pub fn task2_brute_force(input: ())"), None);
+    }
+
+    #[test]
+    fn year_dependencies_are_read_from_the_dependencies_table() {
+        let manifest = "\
+[package]
+name = \"advent_of_code\"
+
+[features]
+aoc_2019 = []
+
+[dependencies]
+aoc_2022 = { path = \"aoc_2022\" }
+common = { path = \"common\" }
+aoc_2021.path = \"aoc_2021\"
+
+[dependencies.aoc_2023]
+path = \"aoc_2023\"
+
+[dev-dependencies]
+aoc_2020 = { path = \"aoc_2020\" }
+";
+        assert_eq!(year_dependencies(manifest), ["aoc_2021", "aoc_2022", "aoc_2023"]);
+    }
+}
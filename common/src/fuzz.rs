@@ -0,0 +1,230 @@
+//! Randomized testing of tasks against invariants or other implementations.
+//!
+//! A day describes its input as a [`Case`]: how to generate a random valid one,
+//! how to make it simpler and how to render it as puzzle input.
+//! [`check`] then tests a property on many generated inputs and shrinks the first failing one
+//! before panicking, so it can be used directly inside of `#[test]` functions:
+//!
+//! ```ignore
+//! #[test]
+//! fn fuzz_alternatives() {
+//!     let task = crate::YEAR.day("day07").unwrap().task("task2").unwrap();
+//!     fuzz::check::<Equations>(200, fuzz::agree(task));
+//! }
+//! ```
+//!
+//! The runs are deterministic. `FUZZ_SEED` and `FUZZ_CASES` override the seed and the number of cases.
+
+use std::fmt::Debug;
+
+use crate::params::Params;
+use crate::Task;
+
+/// The limit of simplification steps, a safeguard against `shrink` implementations that never stop.
+const MAX_SHRINKS: usize = 10_000;
+
+/// A small deterministic pseudo random number generator (splitmix64).
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the inclusive range `min..=max`.
+    pub fn range(&mut self, min: u64, max: u64) -> u64 {
+        assert!(min <= max, "empty range {min}..={max}");
+        match (max - min).checked_add(1) {
+            Some(len) => min + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+}
+
+/// A randomly generated puzzle input.
+pub trait Case: Clone + Debug {
+    fn generate(rng: &mut Rng) -> Self;
+
+    /// Simpler variants of the case, tried in order while shrinking a failure.
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+
+    /// The case in the format of the puzzle input.
+    fn render(&self) -> String;
+}
+
+/// Simpler variants of a list: removing halves, removing single items and shrinking single items.
+pub fn shrink_vec<T: Clone>(items: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut out = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        out.push(items[..half].to_vec());
+        out.push(items[half..].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut shorter = items.to_vec();
+        shorter.remove(i);
+        out.push(shorter);
+    }
+    for (i, item) in items.iter().enumerate() {
+        for simpler in shrink(item) {
+            let mut changed = items.to_vec();
+            changed[i] = simpler;
+            out.push(changed);
+        }
+    }
+    out
+}
+
+/// Smaller numbers down to `min`, starting with `min` itself and getting closer to `value` by halving the distance.
+pub fn shrink_u64(value: u64, min: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    let mut delta = value.saturating_sub(min);
+    while delta > 0 {
+        out.push(value - delta);
+        delta /= 2;
+    }
+    out
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    std::env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Tests `property` on `cases` generated inputs.
+/// Panics with the smallest failing input that could be found by shrinking the first failure.
+pub fn check<C: Case>(cases: usize, property: impl Fn(&str) -> Result<(), String>) {
+    let seed = env_or("FUZZ_SEED", 0x0A0C_2015);
+    let cases = env_or("FUZZ_CASES", cases);
+    let mut rng = Rng::new(seed);
+
+    for i in 0..cases {
+        let case = C::generate(&mut rng);
+        if let Err(err) = property(&case.render()) {
+            let (case, err) = shrink(case, err, &property);
+            panic!(
+                "property failed on case {i} of seed {seed}: {err}\nminimal input:\n{}",
+                case.render()
+            );
+        }
+    }
+}
+
+fn shrink<C: Case>(mut case: C, mut err: String, property: impl Fn(&str) -> Result<(), String>) -> (C, String) {
+    let mut steps = 0;
+    'simplify: while steps < MAX_SHRINKS {
+        for simpler in case.shrink() {
+            steps += 1;
+            if let Err(e) = property(&simpler.render()) {
+                case = simpler;
+                err = e;
+                continue 'simplify;
+            }
+        }
+        break;
+    }
+    (case, err)
+}
+
+/// A property requiring all implementations of `task` to give the same answer with the default parameters.
+pub fn agree(task: &Task) -> impl Fn(&str) -> Result<(), String> + '_ {
+    move |input| {
+        let params = Params::defaults(task.params);
+        let expected = task.run(input.as_bytes(), &params);
+        for alternative in task.alternatives {
+            let actual = alternative.run(input.as_bytes(), &params);
+            if actual != expected {
+                return Err(format!("{} returned {expected:?} but {} returned {actual:?}", task.name, alternative.name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A property comparing the answer of `task` with the default parameters to a reference implementation.
+pub fn matches<F, T>(task: &Task, reference: F) -> impl Fn(&str) -> Result<(), String> + '_
+where
+    F: Fn(&str) -> T + 'static,
+    T: ToString,
+{
+    move |input| {
+        let params = Params::defaults(task.params);
+        let expected = reference(input).to_string();
+        match task.run(input.as_bytes(), &params) {
+            Ok(actual) if actual == expected => Ok(()),
+            Ok(actual) => Err(format!("{} returned {actual} but the reference {expected}", task.name)),
+            Err(err) => Err(format!("{} failed: {err}", task.name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Numbers(Vec<u64>);
+
+    impl Case for Numbers {
+        fn generate(rng: &mut Rng) -> Self {
+            let len = rng.range(1, 20);
+            Self((0..len).map(|_| rng.range(0, 1000)).collect())
+        }
+
+        fn shrink(&self) -> Vec<Self> {
+            shrink_vec(&self.0, |n| shrink_u64(*n, 0)).into_iter().map(Self).collect()
+        }
+
+        fn render(&self) -> String {
+            self.0.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(",")
+        }
+    }
+
+    fn no_large_numbers(input: &str) -> Result<(), String> {
+        match input.split(',').filter_map(|n| n.parse::<u64>().ok()).find(|&n| n >= 500) {
+            Some(n) => Err(format!("found {n}")),
+            None => Ok(()),
+        }
+    }
+
+    #[test]
+    fn shrinks_to_minimal_case() {
+        let case = Numbers(vec![3, 800, 12, 999]);
+        let err = no_large_numbers(&case.render()).unwrap_err();
+        let (case, err) = shrink(case, err, no_large_numbers);
+        assert_eq!(case.render(), "500");
+        assert_eq!(err, "found 500");
+    }
+
+    #[test]
+    #[should_panic(expected = "minimal input:\n500")]
+    fn check_reports_failures() {
+        check::<Numbers>(100, no_large_numbers);
+    }
+
+    #[test]
+    fn rng_ranges() {
+        let mut rng = Rng::new(1);
+        assert!((0..1000).map(|_| rng.range(5, 7)).all(|n| (5..=7).contains(&n)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        rng.range(0, u64::MAX);
+    }
+}
@@ -0,0 +1,236 @@
+//! A rectangular grid of cells stored in a single contiguous buffer.
+//!
+//! [`Grid`] implements [`Input`] for every cell type implementing [`FromChar`].
+//! Days that need to remember special characters like a start `S` while parsing
+//! implement [`Input`] for their own type on top of [`Grid::parse_with`].
+
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry_2d::{Direction, Point};
+use crate::input::{Cause, FromChar, Input, InputError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum GridError<E: 'static + std::error::Error> {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("line {line} has {found} cells but the first line has {expected}")]
+    Ragged { line: usize, expected: usize, found: usize },
+    #[error(transparent)]
+    Cell(InputError<E>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self { cells: vec![fill; width * height], width, height }
+    }
+
+    /// Parses one cell per character with `cell`, ending at the first empty line.
+    /// Fails if the lines differ in length.
+    pub fn parse_with<R, E>(read: R, mut cell: impl FnMut(Point, char) -> Result<T, E>) -> Result<Self, GridError<E>>
+    where
+        R: BufRead,
+        E: 'static + std::error::Error,
+    {
+        Self::parse_rows(read, |y, line, cells| {
+            for (x, char) in line.chars().enumerate() {
+                let value = cell(Point::new(x as u32, y as u32), char)
+                    .map_err(|e| InputError::new(y + 1, x + 1, line, Cause::Parse(e)))?;
+                cells.push(value);
+            }
+            Ok(())
+        })
+    }
+
+    /// Reads the lines up to the first empty one, letting `row` append the cells of each line.
+    fn parse_rows<R, E>(
+        mut read: R,
+        mut row: impl FnMut(usize, &str, &mut Vec<T>) -> Result<(), InputError<E>>,
+    ) -> Result<Self, GridError<E>>
+    where
+        R: BufRead,
+        E: 'static + std::error::Error,
+    {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if read.read_line(&mut line)? == 0 {
+                break;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+
+            let start = cells.len();
+            row(height, line, &mut cells).map_err(GridError::Cell)?;
+
+            let found = cells.len() - start;
+            if height == 0 {
+                width = found;
+            } else if found != width {
+                return Err(GridError::Ragged { line: height + 1, expected: width, found });
+            }
+            height += 1;
+        }
+        Ok(Self { cells, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point).then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds for width {}", self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new((i % width) as u32, (i / width) as u32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point in row-major order whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, t)| predicate(t)).map(|(p, _)| p)
+    }
+
+    /// The cells next to `point` in the given directions which lie inside the grid.
+    pub fn neighbours<'a>(&'a self, point: Point, directions: &'a [Direction]) -> impl Iterator<Item = (Point, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&dir| point.offset(dir))
+            .filter_map(|p| self.get(p).map(|t| (p, t)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => panic!("{point:?} out of bounds for a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{point:?} out of bounds for a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+/// Parses one cell per character with [`FromChar`].
+impl<T: FromChar> Input<'_> for Grid<T>
+where
+    T::Err: 'static,
+{
+    type Error = GridError<T::Err>;
+
+    fn parse<R: BufRead>(read: R) -> Result<Self, Self::Error> {
+        Self::parse_with(read, |_, c| T::from_char(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    #[test]
+    fn parse_and_navigate() {
+        let grid = Grid::<u8>::parse(&b"123\n456\n"[..]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.position(|&t| t == 5), Some(Point::new(1, 1)));
+
+        let neighbours = grid.neighbours(Point::new(0, 0), &Direction::CARDINALS).map(|(_, &t)| t).collect::<Vec<_>>();
+        assert_eq!(neighbours, [4, 2]);
+    }
+
+    #[test]
+    fn markers() {
+        let mut start = None;
+        let grid = Grid::parse_with(&b"S.#\n..E"[..], |p, c| {
+            if c == 'S' {
+                start = Some(p);
+            }
+            Ok::<_, Infallible>(c == '#')
+        })
+        .unwrap();
+        assert_eq!(start, Some(Point::new(0, 0)));
+        assert!(grid[Point::new(2, 0)]);
+    }
+
+    #[test]
+    fn errors() {
+        let ragged = Grid::<char>::parse(&b"abc\nde"[..]);
+        assert!(matches!(ragged, Err(GridError::Ragged { line: 2, expected: 3, found: 2 })));
+        let invalid = Grid::<u8>::parse(&b"12\n3x"[..]);
+        assert!(matches!(invalid, Err(GridError::Cell(InputError { line: 2, column: 2, .. }))));
+        let non_ascii = Grid::<char>::parse("ab\ncä".as_bytes()).unwrap();
+        assert_eq!(non_ascii[Point::new(1, 1)], 'ä');
+    }
+}
@@ -1,14 +1,28 @@
 use std::{convert::Infallible, marker::PhantomData, str::FromStr};
 
+pub use common_macros::FromChar;
+
 pub trait FromChar: Sized {
     type Err: std::error::Error;
     fn from_char(c: char) -> Result<Self, Self::Err>;
 }
 
+/// The fast path of [`FromChar`] for inputs made of ASCII characters.
+/// Callers only pass ASCII bytes and report anything else themselves.
+pub trait FromByte: Sized {
+    type Err: std::error::Error;
+    fn from_byte(b: u8) -> Result<Self, Self::Err>;
+}
+
 #[derive(thiserror::Error, Debug)]
 #[error("Character was not a digit")]
 pub struct NonDigitChar;
 
+/// A character without a matching variant of a type deriving [`FromChar`](macro@FromChar).
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Unexpected character {0:?}")]
+pub struct UnexpectedChar(pub char);
+
 macro_rules! impl_from_char_int {
     ($($ty:ty),*) => {
         $(
@@ -23,6 +37,17 @@ macro_rules! impl_from_char_int {
                 Ok(i as $ty)
             }
         }
+
+        impl FromByte for $ty {
+            type Err = NonDigitChar;
+            fn from_byte(b: u8) -> Result<Self, Self::Err> {
+                if b.is_ascii_digit() == false {
+                    return Err(NonDigitChar);
+                }
+
+                Ok((b - b'0') as $ty)
+            }
+        }
         )*
     };
 }
@@ -37,8 +62,16 @@ impl FromChar for char {
     }
 }
 
+impl FromByte for char {
+    type Err = Infallible;
+
+    fn from_byte(b: u8) -> Result<Self, Self::Err> {
+        Ok(b as char)
+    }
+}
+
 pub struct Charwise<T: FromChar> {
-    str: std::vec::IntoIter<u8>,
+    chars: std::vec::IntoIter<char>,
     _t: PhantomData<T>,
 }
 
@@ -47,7 +80,7 @@ impl<T: FromChar> FromStr for Charwise<T> {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            str: s.to_owned().into_bytes().into_iter(),
+            chars: s.chars().collect::<Vec<_>>().into_iter(),
             _t: PhantomData,
         })
     }
@@ -57,6 +90,36 @@ impl<T: FromChar> Iterator for Charwise<T> {
     type Item = Result<T, T::Err>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.str.next().map(|b| T::from_char(b as char))
+        self.chars.next().map(T::from_char)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::CommaSeparated;
+
+    #[derive(Debug, PartialEq, FromChar)]
+    enum Tile {
+        #[char('.')]
+        Free,
+        #[char('#', '@')]
+        Wall,
+    }
+
+    #[test]
+    fn derive_from_char() {
+        assert_eq!(Tile::from_char('.').unwrap(), Tile::Free);
+        assert_eq!(Tile::from_char('@').unwrap(), Tile::Wall);
+        assert_eq!(Tile::from_byte(b'#').unwrap(), Tile::Wall);
+        assert_eq!(Tile::from_char('x').unwrap_err(), UnexpectedChar('x'));
+    }
+
+    #[test]
+    fn non_ascii() {
+        let chars = Charwise::<char>::from_str("aä€").unwrap().collect::<Result<String, _>>().unwrap();
+        assert_eq!(chars, "aä€");
+        let parts = CommaSeparated::<String>::from_str("ä,€,x").unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(parts, ["ä", "€", "x"]);
     }
 }
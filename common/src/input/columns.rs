@@ -0,0 +1,257 @@
+//! Tables of values aligned in columns, like the crate stacks of 2022 day 5:
+//!
+//! ```text
+//!     [D]
+//! [N] [C]
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//!
+//! [`Columns`] keeps the text of every cell, which can be accessed by row or by column
+//! and parsed with a different [`FromStr`] type for each row or column.
+
+use std::convert::Infallible;
+use std::io::BufRead;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::str::FromStr;
+
+use super::error::{parse_located, read_line};
+use super::{Input, InputError};
+
+/// How [`Columns`] splits a line into cells.
+pub trait Layout {
+    /// The byte ranges of the cells of `line`.
+    fn cells(line: &str) -> Vec<Range<usize>>;
+}
+
+/// Cells separated by runs of whitespace, like `Time:      7  15   30`.
+#[derive(Debug, Clone, Copy)]
+pub struct Fields;
+
+impl Layout for Fields {
+    fn cells(line: &str) -> Vec<Range<usize>> {
+        line.split_whitespace()
+            .map(|field| {
+                let start = field.as_ptr() as usize - line.as_ptr() as usize;
+                start..start + field.len()
+            })
+            .collect()
+    }
+}
+
+/// Cells of `WIDTH` characters each, like `[Z] [M] [P]`.
+#[derive(Debug, Clone, Copy)]
+pub struct Fixed<const WIDTH: usize>;
+
+impl<const WIDTH: usize> Layout for Fixed<WIDTH> {
+    fn cells(line: &str) -> Vec<Range<usize>> {
+        let mut starts = line.char_indices().map(|(i, _)| i).step_by(WIDTH).peekable();
+        let mut cells = Vec::new();
+        while let Some(start) = starts.next() {
+            cells.push(start..starts.peek().copied().unwrap_or(line.len()));
+        }
+        cells
+    }
+}
+
+/// Where the labels of a [`Columns`] table are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelPosition {
+    None,
+    /// The first line labels the columns.
+    Header,
+    /// The last line labels the columns.
+    Footer,
+    /// The first cell of each line labels its row.
+    Column,
+}
+
+pub trait Labels {
+    const POSITION: LabelPosition;
+}
+
+macro_rules! labels {
+    ($($(#[$meta:meta])* $name:ident => $position:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+
+            impl Labels for $name {
+                const POSITION: LabelPosition = LabelPosition::$position;
+            }
+        )*
+    };
+}
+
+labels! {
+    NoLabels => None;
+    /// The first line labels the columns.
+    HeaderRow => Header;
+    /// The last line labels the columns, like the stack numbers below the crates.
+    FooterRow => Footer;
+    /// The first cell of each line labels its row, like `Time:`.
+    LabelColumn => Column;
+}
+
+#[derive(Debug, Clone)]
+struct Row {
+    /// The line of the input, starting at 1.
+    line: usize,
+    text: String,
+    cells: Vec<Range<usize>>,
+}
+
+impl Row {
+    /// The trimmed text of the cell, [`None`] if it is empty or missing.
+    fn cell(&self, x: usize) -> Option<&str> {
+        let cell = self.text[self.cells.get(x)?.clone()].trim();
+        (!cell.is_empty()).then_some(cell)
+    }
+
+    fn parse<T: FromStr>(&self, x: usize) -> Option<Result<T, InputError<T::Err>>> {
+        self.cell(x).map(|cell| parse_located(self.line, &self.text, cell))
+    }
+
+    /// The texts of all cells for a row of column labels.
+    fn into_labels(self) -> Vec<String> {
+        self.cells.iter().map(|cells| self.text[cells.clone()].trim().to_owned()).collect()
+    }
+
+    /// Removes the first cell labelling the row.
+    fn take_label(&mut self) -> String {
+        if self.cells.is_empty() {
+            return String::new();
+        }
+        self.text[self.cells.remove(0)].trim().to_owned()
+    }
+}
+
+/// A table of cells split by the layout `L` with labels at the position given by `H`.
+/// The table ends at the first empty line.
+#[derive(Debug, Clone)]
+pub struct Columns<L = Fields, H = NoLabels> {
+    labels: Vec<String>,
+    rows: Vec<Row>,
+    width: usize,
+    _t: PhantomData<(L, H)>,
+}
+
+impl<L, H> Columns<L, H> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The labels of the columns or rows depending on `H`.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// The index of the column or row labeled `label`.
+    pub fn label_index(&self, label: &str) -> Option<usize> {
+        self.labels.iter().position(|l| l == label)
+    }
+
+    /// The trimmed text of a cell, [`None`] if it is empty or missing.
+    pub fn cell(&self, x: usize, y: usize) -> Option<&str> {
+        self.rows.get(y)?.cell(x)
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = Option<&str>> {
+        let row = &self.rows[y];
+        (0..self.width).map(move |x| row.cell(x))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = Option<&str>> {
+        self.rows.iter().map(move |row| row.cell(x))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&str>>> {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&str>>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Parses the non-empty cells of row `y` from left to right.
+    pub fn parse_row<T: FromStr>(&self, y: usize) -> impl Iterator<Item = Result<T, InputError<T::Err>>> + '_ {
+        let row = &self.rows[y];
+        (0..self.width).filter_map(move |x| row.parse(x))
+    }
+
+    /// Parses the non-empty cells of column `x` from top to bottom.
+    pub fn parse_column<T: FromStr>(&self, x: usize) -> impl DoubleEndedIterator<Item = Result<T, InputError<T::Err>>> + '_ {
+        self.rows.iter().filter_map(move |row| row.parse(x))
+    }
+}
+
+impl<L: Layout, H: Labels> Input<'_> for Columns<L, H> {
+    type Error = InputError<Infallible>;
+
+    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
+        let mut rows = Vec::new();
+        let mut line = 0;
+        loop {
+            let mut text = String::new();
+            match read_line(&mut read, &mut text, &mut line) {
+                None => break,
+                Some(res) => res?,
+            }
+            text.truncate(text.trim_end().len());
+            if text.is_empty() {
+                break;
+            }
+            let cells = L::cells(&text);
+            rows.push(Row { line, text, cells });
+        }
+
+        let labels = match H::POSITION {
+            LabelPosition::None => Vec::new(),
+            LabelPosition::Header if rows.is_empty() => Vec::new(),
+            LabelPosition::Header => rows.remove(0).into_labels(),
+            LabelPosition::Footer => rows.pop().map(Row::into_labels).unwrap_or_default(),
+            LabelPosition::Column => rows.iter_mut().map(Row::take_label).collect(),
+        };
+
+        let mut width = rows.iter().map(|row| row.cells.len()).max().unwrap_or(0);
+        if matches!(H::POSITION, LabelPosition::Header | LabelPosition::Footer) {
+            width = width.max(labels.len());
+        }
+        Ok(Self { labels, rows, width, _t: PhantomData })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STACKS: &[u8] = b"    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n";
+
+    #[test]
+    fn fixed_width() {
+        let stacks = Columns::<Fixed<4>, FooterRow>::parse(STACKS).unwrap();
+        assert_eq!(stacks.labels(), ["1", "2", "3"]);
+        assert_eq!((stacks.width(), stacks.height()), (3, 3));
+        assert_eq!(stacks.row(0).collect::<Vec<_>>(), [None, Some("[D]"), None]);
+        assert_eq!(stacks.column(1).flatten().collect::<Vec<_>>(), ["[D]", "[C]", "[M]"]);
+
+        let err = stacks.parse_column::<u32>(2).next().unwrap().unwrap_err();
+        assert_eq!((err.line, err.column), (3, 9));
+    }
+
+    #[test]
+    fn fields() {
+        let races = Columns::<Fields, LabelColumn>::parse(&b"Time:      7  15   30\nDistance:  9  40  200\n"[..]).unwrap();
+        assert_eq!(races.labels(), ["Time:", "Distance:"]);
+        let distances = races.parse_row::<u32>(races.label_index("Distance:").unwrap());
+        assert_eq!(distances.map(Result::unwrap).collect::<Vec<_>>(), [9, 40, 200]);
+        let columns = races.columns().map(|c| c.flatten().collect::<Vec<_>>()).collect::<Vec<_>>();
+        assert_eq!(columns, [["7", "9"], ["15", "40"], ["30", "200"]]);
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt::Display;
+use std::io::BufRead;
+
+/// An error of reading or parsing a part of the input together with where in the input it occurred.
+#[derive(Debug)]
+pub struct InputError<E> {
+    /// The line of the input, starting at 1.
+    pub line: usize,
+    /// The column of the start of the value that failed to parse, starting at 1.
+    /// 0 if the error does not belong to a single column, like a failed read.
+    pub column: usize,
+    /// The line containing the value.
+    pub snippet: String,
+    pub source: Cause<E>,
+}
+
+/// What went wrong at the position of an [`InputError`].
+#[derive(Debug, thiserror::Error)]
+pub enum Cause<E> {
+    #[error("{0}")]
+    Parse(E),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("unexpected character {0:?}")]
+    Unexpected(char),
+}
+
+impl<E> InputError<E> {
+    pub fn new(line: usize, column: usize, snippet: &str, source: Cause<E>) -> Self {
+        Self { line, column, snippet: snippet.trim_end().to_owned(), source }
+    }
+
+    /// A failure to read `line`, including it not being valid UTF-8.
+    pub fn io(line: usize, err: std::io::Error) -> Self {
+        Self::new(line, 0, "", Cause::Io(err))
+    }
+}
+
+impl<E: Display> Display for InputError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}", self.line)?;
+        if self.column > 0 {
+            write!(f, ", col {}", self.column)?;
+        }
+        write!(f, ": {}", self.source)?;
+        if !self.snippet.is_empty() {
+            write!(f, "\n    {}", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for InputError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.source {
+            Cause::Parse(e) => Some(e),
+            Cause::Io(e) => Some(e),
+            Cause::Unexpected(_) => None,
+        }
+    }
+}
+
+/// Implements `From<InputError<$error>>` for an error enum whose values parse into `$error` themselves,
+/// by boxing it into the `Input(Box<InputError<$error>>)` variant the enum has to declare.
+///
+/// ```
+/// #[derive(Debug, thiserror::Error)]
+/// pub enum Error {
+///     #[error("invalid move {0}")]
+///     Move(String),
+///     #[error(transparent)]
+///     Input(Box<common::input::InputError<Error>>),
+/// }
+///
+/// common::box_input_error!(Error);
+/// ```
+#[macro_export]
+macro_rules! box_input_error {
+    ($error:ty) => {
+        impl From<$crate::input::InputError<$error>> for $error {
+            fn from(e: $crate::input::InputError<$error>) -> Self {
+                Self::Input(Box::new(e))
+            }
+        }
+    };
+}
+
+/// Reads the next line into `buf` and counts it in `line`.
+/// Returns [`None`] at the end of the input so adapters can use `?` in [`Iterator::next`].
+pub(crate) fn read_line<E>(read: &mut dyn BufRead, buf: &mut String, line: &mut usize) -> Option<Result<(), InputError<E>>> {
+    match read.read_line(buf) {
+        Ok(0) => None,
+        Ok(_) => {
+            *line += 1;
+            Some(Ok(()))
+        }
+        Err(e) => {
+            *line += 1;
+            Some(Err(InputError::io(*line, e)))
+        }
+    }
+}
+
+/// Parses `value`, which is a part of `line`, and locates the error if it fails.
+/// `value` has to be a subslice of `line` to determine the column.
+pub(crate) fn parse_located<T: std::str::FromStr>(number: usize, line: &str, value: &str) -> Result<T, InputError<T::Err>> {
+    T::from_str(value).map_err(|e| {
+        let offset = (value.as_ptr() as usize).saturating_sub(line.as_ptr() as usize).min(line.len());
+        InputError::new(number, line[..offset].chars().count() + 1, line, Cause::Parse(e))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, GridError};
+    use crate::input::{Chunked, CommaSeparated, Grouped, Input, LineSeparated, Linewise, Multiline, NonDigitChar};
+
+    fn first_error<T, E>(iter: impl IntoIterator<Item = Result<T, E>>) -> E {
+        iter.into_iter().find_map(Result::err).expect("input should fail to parse")
+    }
+
+    #[test]
+    fn locates_errors() {
+        let err = first_error(Linewise::<u32>::parse(&b"1\n2\n x3\n"[..]).unwrap());
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (3, 2, " x3"));
+        assert_eq!(err.to_string(), "line 3, col 2: invalid digit found in string\n     x3");
+
+        let err = first_error(CommaSeparated::<u32>::parse(&b"1,22,4x,5"[..]).unwrap());
+        assert_eq!((err.line, err.column), (1, 6));
+
+        let err = first_error(Grouped::<u32>::parse(&b"1\n2\n\n3\nx"[..]).unwrap());
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (5, 1, "x"));
+    }
+
+    #[test]
+    fn locates_sections() {
+        let input = LineSeparated::<Linewise<u32>, Grid<u8>>::parse(&b"1\n2\n\n12\n3x"[..]);
+        let err = input.err().expect("the second section should fail to parse");
+        assert_eq!(err.line, 4);
+        let inner = err.source.downcast_ref::<GridError<NonDigitChar>>();
+        assert!(matches!(inner, Some(GridError::Cell(InputError { line: 2, column: 2, .. }))));
+    }
+
+    fn is_io<T, E>(item: Option<Result<T, InputError<E>>>, line: usize) -> bool {
+        matches!(item, Some(Err(InputError { line: l, source: Cause::Io(_), .. })) if l == line)
+    }
+
+    #[test]
+    fn reports_invalid_utf8() {
+        const INPUT: &[u8] = b"1\n\xff\n3\n4\n";
+        assert!(is_io(Linewise::<u32>::parse(INPUT).unwrap().nth(1), 2));
+        assert!(is_io(CommaSeparated::<u32>::parse(INPUT).unwrap().nth(1), 2));
+        assert!(is_io(Multiline::<String, 2, false>::parse(INPUT).unwrap().next(), 2));
+        assert!(is_io(Chunked::<u32, 2, false>::parse(INPUT).unwrap().next(), 2));
+        assert!(is_io(Grouped::<u32>::parse(INPUT).unwrap().next(), 2));
+        assert!(matches!(Grid::<u8>::parse(INPUT), Err(GridError::Io(_))));
+        assert!(LineSeparated::<Linewise<u32>, Linewise<u32>>::parse(INPUT).is_err());
+    }
+
+    #[test]
+    fn rejects_non_digits() {
+        let err = Grid::<u8>::parse(&b"123\n4+6"[..]).unwrap_err();
+        assert!(matches!(err, GridError::Cell(InputError { line: 2, column: 2, source: Cause::Parse(NonDigitChar), .. })));
+        assert_eq!(err.to_string(), "line 2, col 2: Character was not a digit\n    4+6");
+    }
+}
@@ -4,7 +4,8 @@ use std::iter::*;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::str::FromStr;
-use super::Input;
+use super::error::parse_located;
+use super::{Input, InputError, InputSource};
 
 pub fn parse_lines<E>(
     reader: &mut impl BufRead,
@@ -23,8 +24,7 @@ where
 }
 
 pub struct Chunked<'a, T: FromStr, const N: usize, const PADDED: bool> {
-    read: Box<dyn 'a + BufRead>,
-    string: String,
+    source: InputSource<'a>,
     _t: PhantomData<T>,
 }
 
@@ -32,26 +32,29 @@ impl<'a, T: FromStr, const N: usize, const PADDED: bool> Input<'a> for Chunked<'
     type Error = Infallible;
 
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
-        Ok(Self {
-            read: Box::new(read),
-            string: String::with_capacity(256),
-            _t: PhantomData::default(),
-        })
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        Ok(Self { source, _t: PhantomData })
     }
 }
 
 impl<T: FromStr, const N: usize, const PADDED: bool> Iterator for Chunked<'_, T, N, PADDED> {
-    type Item = Result<[T; N], T::Err>;
+    type Item = Result<[T; N], InputError<T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut array: [MaybeUninit<T>; N] = std::array::from_fn(|_| MaybeUninit::uninit());
         for i in 0..N {
-            let read = self.read.read_line(&mut self.string).unwrap();
-            if read == 0 {
-                return None;
+            if let Err(e) = self.source.next_line()? {
+                return Some(Err(e));
             }
-            let res = T::from_str(self.string.trim());
-            self.string.clear();
+            let line = self.source.current_line();
+            let res = parse_located(self.source.line(), line, line.trim());
             let t = match res {
                 Ok(t) => t,
                 Err(e) => return Some(Err(e)),
@@ -60,8 +63,7 @@ impl<T: FromStr, const N: usize, const PADDED: bool> Iterator for Chunked<'_, T,
         }
 
         if PADDED {
-            let _ = self.read.read_line(&mut self.string);
-            self.string.clear();
+            let _ = self.source.next_line::<T::Err>();
         }
 
         Some(Ok(array.map(|x| unsafe { MaybeUninit::assume_init(x) })))
@@ -69,8 +71,7 @@ impl<T: FromStr, const N: usize, const PADDED: bool> Iterator for Chunked<'_, T,
 }
 
 pub struct Grouped<'a, T: FromStr> {
-    read: Box<dyn 'a + BufRead>,
-    string: String,
+    source: InputSource<'a>,
     _t: PhantomData<T>,
 }
 
@@ -78,28 +79,36 @@ impl<'a, T: FromStr> Input<'a> for Grouped<'a, T> {
     type Error = Infallible;
 
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
-        Ok(Self {
-            read: Box::new(read),
-            string: String::with_capacity(256),
-            _t: PhantomData::default(),
-        })
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        Ok(Self { source, _t: PhantomData })
     }
 }
 
 impl<T: FromStr> Iterator for Grouped<'_, T> {
-    type Item = Result<Vec<T>, T::Err>;
+    type Item = Result<Vec<T>, InputError<T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let mut vec = Vec::new();
         loop {
-            let read = self.read.read_line(&mut self.string).unwrap();
-            let trimmed = self.string.trim();
-            if read == 0 || trimmed.len() == 0 {
+            match self.source.next_line() {
+                None => break,
+                Some(Err(e)) => return Some(Err(e)),
+                Some(Ok(_)) => {}
+            }
+            let line = self.source.current_line();
+            let trimmed = line.trim();
+            if trimmed.len() == 0 {
                 break;
             }
 
-            let res = T::from_str(trimmed);
-            self.string.clear();
+            let res = parse_located(self.source.line(), line, trimmed);
             let t = match res {
                 Ok(t) => t,
                 Err(e) => return Some(Err(e)),
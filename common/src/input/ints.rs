@@ -0,0 +1,155 @@
+//! Every integer in a text regardless of what surrounds it, like `Sensor at x=2, y=-18`.
+//!
+//! A `-` directly in front of digits is a sign unless it follows a digit itself,
+//! so ranges like `1-3` are read as `1` and `3`.
+
+use std::error::Error;
+use std::io::BufRead;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use super::Input;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IntsError<E> {
+    #[error("invalid integer {value:?}: {source}")]
+    Parse { value: String, source: E },
+    #[error("expected {expected} integers, found {actual}")]
+    Count { expected: usize, actual: usize },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The integers of `s` in order of appearance, including their signs.
+fn find_ints(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        let start = i + bytes[i..].iter().position(u8::is_ascii_digit)?;
+        let len = bytes[start..].iter().position(|b| !b.is_ascii_digit()).unwrap_or(bytes.len() - start);
+        i = start + len;
+        let signed = start > 0 && bytes[start - 1] == b'-' && (start < 2 || !bytes[start - 2].is_ascii_digit());
+        Some(&s[start - signed as usize..i])
+    })
+}
+
+fn parse_ints<T: FromStr>(s: &str) -> Result<Vec<T>, IntsError<T::Err>> {
+    find_ints(s)
+        .map(|value| value.parse().map_err(|source| IntsError::Parse { value: value.to_owned(), source }))
+        .collect()
+}
+
+/// All integers found in a line or the whole input, see the [module documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ints<T>(Vec<T>);
+
+impl<T> Ints<T> {
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Ints<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T> IntoIterator for Ints<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: FromStr> FromStr for Ints<T> {
+    type Err = IntsError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_ints(s).map(Self)
+    }
+}
+
+impl<T: FromStr> Input<'_> for Ints<T>
+where
+    IntsError<T::Err>: 'static + Error,
+{
+    type Error = IntsError<T::Err>;
+
+    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
+        let mut s = String::new();
+        read.read_to_string(&mut s)?;
+        s.parse()
+    }
+}
+
+/// Exactly `N` integers, like the four coordinates of `p=0,4 v=3,-3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntsN<T, const N: usize>([T; N]);
+
+impl<T, const N: usize> IntsN<T, N> {
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Deref for IntsN<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &[T; N] {
+        &self.0
+    }
+}
+
+impl<T: FromStr, const N: usize> FromStr for IntsN<T, N> {
+    type Err = IntsError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ints = parse_ints(s)?;
+        let actual = ints.len();
+        ints.try_into().map(Self).map_err(|_| IntsError::Count { expected: N, actual })
+    }
+}
+
+impl<T: FromStr, const N: usize> Input<'_> for IntsN<T, N>
+where
+    IntsError<T::Err>: 'static + Error,
+{
+    type Error = IntsError<T::Err>;
+
+    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
+        let mut s = String::new();
+        read.read_to_string(&mut s)?;
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_ints() {
+        let ints = "Sensor at x=2, y=-18: closest beacon is at x=-2, y=15".parse::<Ints<i32>>().unwrap();
+        assert_eq!(*ints, [2, -18, -2, 15]);
+
+        let ranges = Ints::<u32>::parse(&b"2-4,6-8\n10-12"[..]).unwrap();
+        assert_eq!(ranges.into_inner(), [2, 4, 6, 8, 10, 12]);
+
+        assert!(matches!("a -1".parse::<Ints<u32>>(), Err(IntsError::Parse { value, .. }) if value == "-1"));
+    }
+
+    #[test]
+    fn fixed_count() {
+        let robot = "p=0,4 v=3,-3".parse::<IntsN<i64, 4>>().unwrap();
+        assert_eq!(robot.into_inner(), [0, 4, 3, -3]);
+
+        let err = "p=0,4 v=3".parse::<IntsN<i64, 4>>().unwrap_err();
+        assert!(matches!(err, IntsError::Count { expected: 4, actual: 3 }));
+        assert_eq!(err.to_string(), "expected 4 integers, found 3");
+    }
+}
@@ -1,12 +1,12 @@
 use super::*;
+use super::error::parse_located;
 use std::{convert::Infallible, marker::PhantomData, str::FromStr};
 
 /// Adapter iterator reading from an underlying stream converting each line individually.
 /// [`Iterator::next()`] may yield a [`Result::Err`] after which further iteration may become unstable.
 /// (Though this will never lead to UB)
 pub struct Linewise<'a, T: FromStr> {
-    read: Box<dyn 'a + BufRead>,
-    string: String,
+    source: InputSource<'a>,
     _t: PhantomData<T>,
 }
 
@@ -14,30 +14,34 @@ impl<'a, T: FromStr> Input<'a> for Linewise<'a, T> {
     type Error = Infallible;
 
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
-        Ok(Self {
-            read: Box::new(read),
-            string: String::with_capacity(256),
-            _t: PhantomData::default(),
-        })
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        Ok(Self { source, _t: PhantomData })
     }
 }
 
 impl<T: FromStr> Iterator for Linewise<'_, T> {
-    type Item = Result<T, T::Err>;
+    type Item = Result<T, InputError<T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let read = self.read.read_line(&mut self.string).unwrap();
-        if read == 0 {
-            return None;
+        if let Err(e) = self.source.next_line()? {
+            return Some(Err(e));
         }
-        let t = T::from_str(self.string.trim());
-        self.string.clear();
-        return Some(t);
+        let line = self.source.current_line();
+        Some(parse_located(self.source.line(), line, line.trim()))
     }
 }
 
+/// Adapter iterator joining every `N` lines into one value, skipping a separating line after each if `PADDED`.
+/// The lines are joined with `\n` regardless of the line endings of the input.
 pub struct Multiline<'a, T: FromStr, const N: usize, const PADDED: bool> {
-    read: Box<dyn 'a + BufRead>,
+    source: InputSource<'a>,
     string: String,
     _t: PhantomData<T>,
 }
@@ -46,31 +50,39 @@ impl<'a, T: FromStr, const N: usize, const PADDED: bool> Input<'a> for Multiline
     type Error = Infallible;
 
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
-        Ok(Self {
-            read: Box::new(read),
-            string: String::with_capacity(256),
-            _t: PhantomData::default(),
-        })
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        Ok(Self { source, string: String::with_capacity(256), _t: PhantomData })
     }
 }
 
 impl<T: FromStr, const N: usize, const PADDED: bool> Iterator for Multiline<'_, T, N, PADDED> {
-    type Item = Result<T, T::Err>;
+    /// Errors point to the first line of the value.
+    type Item = Result<T, InputError<T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.string.clear();
+        let first = self.source.line() + 1;
         for _ in 0..N {
-            let read = self.read.read_line(&mut self.string).unwrap();
-            if read == 0 {
-                return None;
+            match self.source.next_line()? {
+                Ok(line) => {
+                    self.string.push_str(line.trim_end_matches(['\r', '\n']));
+                    self.string.push('\n');
+                }
+                Err(e) => return Some(Err(e)),
             }
         }
 
-        let res = T::from_str(self.string.trim());
+        let res = parse_located(first, &self.string, self.string.trim());
 
         if PADDED {
-            let _ = self.read.read_line(&mut self.string);
-            self.string.clear();
+            let _ = self.source.next_line::<T::Err>();
         }
 
         Some(res)
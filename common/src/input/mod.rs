@@ -1,15 +1,68 @@
-pub use self::{chars::*, group::*, lines::*, separated::*};
+pub use self::{chars::*, columns::*, error::{Cause, InputError}, group::*, ints::*, lines::*, sections::*, separated::*, source::InputSource};
+pub use crate::grid::Grid;
 use std::io::BufRead;
 
 pub mod chars;
-pub mod digits;
+pub mod columns;
+mod error;
 pub mod group;
+pub mod ints;
 pub mod lines;
+pub mod sections;
 pub mod separated;
+pub mod source;
 
 pub trait Input<'a>: Sized {
     type Error: 'static + std::error::Error;
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error>;
+
+    /// Parses the complete input held in memory, which lets the result borrow from it.
+    /// By default the bytes are read like any other stream.
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse(bytes)
+    }
+
+    /// Parses a source shared with other adapters, borrowing from it if it is held in memory.
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        match source.into_bytes() {
+            Ok(bytes) => Self::parse_bytes(bytes),
+            Err(source) => Self::parse(source),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BorrowError {
+    #[error("Borrowed input can only be parsed from memory")]
+    Streamed,
+    #[error(transparent)]
+    Utf8(#[from] std::str::Utf8Error),
+}
+
+/// The whole input without trailing whitespace.
+impl<'a> Input<'a> for &'a [u8] {
+    type Error = BorrowError;
+
+    fn parse<R: 'a + BufRead>(_: R) -> Result<Self, Self::Error> {
+        Err(BorrowError::Streamed)
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(bytes.trim_ascii_end())
+    }
+}
+
+/// The whole input without trailing whitespace.
+impl<'a> Input<'a> for &'a str {
+    type Error = BorrowError;
+
+    fn parse<R: 'a + BufRead>(_: R) -> Result<Self, Self::Error> {
+        Err(BorrowError::Streamed)
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Ok(std::str::from_utf8(bytes)?.trim_end())
+    }
 }
 
 impl Input<'_> for String {
@@ -0,0 +1,247 @@
+//! Inputs made of several parts separated by blank lines.
+//!
+//! [`Sections`] parses a tuple of up to six sections, each with its own [`Input`] type,
+//! or any number of sections of the same type as a [`Vec`].
+//! The last element of a tuple receives the rest of the input,
+//! so it can be a `Sections<Vec<T>>` itself for a fixed prefix followed by repeated parts:
+//!
+//! ```ignore
+//! // seeds: 79 14 55 13
+//! //
+//! // seed-to-soil map:
+//! // 50 98 2
+//! // ...
+//! type Almanac = Sections<(String, Sections<Vec<Headed<String, Linewise<Range>>>>)>;
+//! ```
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use super::error::parse_located;
+use super::{Cause, Input, InputError, InputSource};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SectionError {
+    #[error("missing section {0}")]
+    Missing(usize),
+    #[error("failed to read line {line}: {source}")]
+    Io { line: usize, source: std::io::Error },
+    #[error("in section {section} starting at line {line}: {source}")]
+    Parse { section: usize, line: usize, source: Box<dyn Error> },
+}
+
+/// Sections separated by blank lines, see the [module documentation](self).
+#[derive(Debug)]
+pub struct Sections<T>(T);
+
+impl<T> Sections<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Splits an [`InputSource`] into sections and counts them.
+struct Splitter<'a> {
+    source: InputSource<'a>,
+    section: usize,
+}
+
+impl<'a> Splitter<'a> {
+    fn new(source: InputSource<'a>) -> Self {
+        Self { source, section: 0 }
+    }
+
+    fn io(e: InputError<Infallible>) -> SectionError {
+        let Cause::Io(source) = e.source else { unreachable!("reading a line only fails with io errors") };
+        SectionError::Io { line: e.line, source }
+    }
+
+    /// Skips blank lines and returns whether there is any input left.
+    fn skip_blank(&mut self) -> Result<bool, SectionError> {
+        loop {
+            match self.source.peek_line::<Infallible>() {
+                None => return Ok(false),
+                Some(Err(e)) => return Err(Self::io(e)),
+                Some(Ok(line)) if line.trim().is_empty() => {}
+                Some(Ok(_)) => return Ok(true),
+            }
+            self.source.next_line::<Infallible>();
+        }
+    }
+
+    /// Parses the lines up to the next blank line as `T`.
+    /// Returns [`None`] if there are no more sections.
+    fn next<T: Input<'a>>(&mut self) -> Result<Option<T>, SectionError> {
+        if !self.skip_blank()? {
+            return Ok(None);
+        }
+        self.section += 1;
+        let start = self.source.line() + 1;
+        let source = self.source.split_section().map_err(Self::io)?;
+        parse(self.section, start, source).map(Some)
+    }
+
+    fn expect<T: Input<'a>>(&mut self) -> Result<T, SectionError> {
+        self.next()?.ok_or(SectionError::Missing(self.section + 1))
+    }
+
+    /// Parses everything after the blank lines following the previous section as `T`.
+    fn rest<T: Input<'a>>(mut self) -> Result<T, SectionError> {
+        if !self.skip_blank()? {
+            return Err(SectionError::Missing(self.section + 1));
+        }
+        self.section += 1;
+        let start = self.source.line() + 1;
+        parse(self.section, start, self.source)
+    }
+}
+
+fn parse<'a, T: Input<'a>>(section: usize, start: usize, source: InputSource<'a>) -> Result<T, SectionError> {
+    T::parse_source(source).map_err(|e| SectionError::Parse { section, line: start, source: Box::new(e) })
+}
+
+macro_rules! impl_sections {
+    ($($init:ident)*; $last:ident) => {
+        impl<'a, $($init: Input<'a>,)* $last: Input<'a>> Input<'a> for Sections<($($init,)* $last,)> {
+            type Error = SectionError;
+
+            fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
+                Self::parse_source(InputSource::from_reader(read))
+            }
+
+            fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+                Self::parse_source(InputSource::from_bytes(bytes))
+            }
+
+            #[allow(non_snake_case)]
+            fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+                let mut splitter = Splitter::new(source);
+                $(let $init = splitter.expect()?;)*
+                let $last = splitter.rest()?;
+                Ok(Self(($($init,)* $last,)))
+            }
+        }
+    };
+}
+
+impl_sections!(A; B);
+impl_sections!(A B; C);
+impl_sections!(A B C; D);
+impl_sections!(A B C D; E);
+impl_sections!(A B C D E; F);
+
+impl<'a, T: Input<'a>> Input<'a> for Sections<Vec<T>> {
+    type Error = SectionError;
+
+    fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        let mut splitter = Splitter::new(source);
+        let mut sections = Vec::new();
+        while let Some(section) = splitter.next()? {
+            sections.push(section);
+        }
+        Ok(Self(sections))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HeadedError<H, B> {
+    #[error(transparent)]
+    Header(InputError<H>),
+    #[error(transparent)]
+    Body(B),
+}
+
+/// A section starting with a header line like `seed-to-soil map:`, parsed as `H`, followed by its body.
+#[derive(Debug)]
+pub struct Headed<H, T> {
+    pub header: H,
+    pub body: T,
+}
+
+impl<'a, H, T> Input<'a> for Headed<H, T>
+where
+    H: FromStr,
+    H::Err: 'static + Error,
+    T: Input<'a>,
+{
+    type Error = HeadedError<H::Err, T::Error>;
+
+    fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(mut source: InputSource<'a>) -> Result<Self, Self::Error> {
+        if let Some(Err(e)) = source.next_line() {
+            return Err(HeadedError::Header(e));
+        }
+        let line = source.current_line();
+        let header = parse_located(1, line, line.trim()).map_err(HeadedError::Header)?;
+        let body = T::parse_source(source).map_err(HeadedError::Body)?;
+        Ok(Self { header, body })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Linewise;
+    use crate::iter_ext::TryIterator;
+
+    const INPUT: &[u8] = b"\
+seeds: 1 2
+
+a-to-b map:
+1 2
+3 4
+
+
+b-to-c map:
+5 6
+";
+
+    type Almanac<'a> = Sections<(String, Sections<Vec<Headed<String, Linewise<'a, String>>>>)>;
+
+    #[test]
+    fn splits_sections() {
+        let (seeds, maps) = Almanac::parse(INPUT).unwrap().into_inner();
+        assert_eq!(seeds, "seeds: 1 2");
+        let maps = maps
+            .into_inner()
+            .into_iter()
+            .map(|m| (m.header, m.body.try_collect2::<Vec<_>>().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(maps, [("a-to-b map:".to_owned(), vec!["1 2".to_owned(), "3 4".to_owned()]), ("b-to-c map:".to_owned(), vec!["5 6".to_owned()])]);
+    }
+
+    #[test]
+    fn borrows_sections() {
+        let (seeds, maps) = Sections::<(&str, Sections<Vec<Headed<String, &str>>>)>::parse_bytes(INPUT).unwrap().into_inner();
+        assert_eq!(seeds, "seeds: 1 2");
+        let bodies = maps.into_inner().into_iter().map(|m| m.body).collect::<Vec<_>>();
+        assert_eq!(bodies, ["1 2\n3 4", "5 6"]);
+    }
+
+    #[test]
+    fn reports_sections() {
+        let missing = Sections::<(Linewise<u32>, String, String)>::parse(&b"1\n\n2\n"[..]);
+        assert!(matches!(missing, Err(SectionError::Missing(3))));
+
+        let err = Sections::<Vec<Headed<u32, ()>>>::parse(&b"1\n\n\nx\n"[..]).unwrap_err();
+        assert!(matches!(err, SectionError::Parse { section: 2, line: 4, .. }));
+        assert_eq!(err.to_string(), "in section 2 starting at line 4: line 1, col 1: invalid digit found in string\n    x");
+    }
+}
@@ -1,13 +1,13 @@
-use std::{str::FromStr, marker::PhantomData, io::{BufRead, Cursor}, convert::Infallible, error::Error, fmt::Display};
+use std::{str::FromStr, marker::PhantomData, io::BufRead, convert::Infallible, error::Error, fmt::Display, ops::Range};
 
-use super::Input;
+use super::error::parse_located;
+use super::{Input, InputError, InputSource};
 
 pub type CommaSeparated<'a, T> = CharSeparated<'a, T, ','>;
 pub type SpaceSeparated<'a, T> = CharSeparated<'a, T, ' '>;
 
 pub struct CharSeparated<'a, T: 'a + FromStr, const C: char> {
-    input: Box<dyn 'a + BufRead>,
-    buffer: String, 
+    source: InputSource<'a>,
     cursor: usize,
     _t: PhantomData<T>,
 }
@@ -16,12 +16,7 @@ impl<'a, T: FromStr, const C: char> FromStr for CharSeparated<'a, T, C> {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            input: Box::new([0u8;0].as_slice()),
-            buffer: s.to_string(),
-            cursor: 0,
-            _t: PhantomData,
-        })
+        Self::parse_source(InputSource::from_string(s.to_owned()))
     }
 }
 
@@ -29,84 +24,311 @@ impl<'a, T: 'a + FromStr, const C: char> Input<'a> for CharSeparated<'a, T, C> {
     type Error = Infallible;
 
     fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
-        Ok(Self {
-            input: Box::new(read),
-            buffer: String::new(),
-            cursor: 0,
-            _t: PhantomData
-        })
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        let cursor = source.current_line().len();
+        Ok(Self { source, cursor, _t: PhantomData })
     }
 }
 
 impl<'a, T: 'a + FromStr, const C: char> Iterator for CharSeparated<'a, T, C> {
-    type Item = Result<T, T::Err>;
+    type Item = Result<T, InputError<T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.cursor >= self.buffer.len() {
-            self.buffer.clear();
+        if self.cursor >= self.source.current_line().len() {
             self.cursor = 0;
-            let n = self.input.read_line(&mut self.buffer).unwrap();
-            if n == 0 { return None; }
+            if let Err(e) = self.source.next_line()? {
+                return Some(Err(e));
+            }
         }
 
-        let read = &self.buffer[self.cursor..];
-        let len = read.chars().take_while(|c| {
-            debug_assert!(c.is_ascii(), "Cannot handle non ascii input");
-            *c != C
-        }).count();
+        let line = self.source.current_line();
+        let read = &line[self.cursor..];
+        let len = read.find(C).unwrap_or(read.len());
 
         let start = self.cursor;
         let end = self.cursor + len;
         // advance the cursor PAST the separator
-        self.cursor += len + 1;
+        self.cursor += len + C.len_utf8();
 
-        Some(T::from_str(&self.buffer[start..end]))
+        Some(parse_located(self.source.line(), line, &line[start..end]))
     }
 }
 
+/// Finds the boundaries between the values of a [`StrSeparated`] input or a [`KeyValue`].
+pub trait Separator {
+    /// Describes the separator in errors.
+    const NAME: &'static str;
+
+    /// The byte range of the first separator in `s`.
+    fn find(s: &str) -> Option<Range<usize>>;
+}
+
+/// Declares a [`Separator`] matching a string literal.
+///
+/// ```
+/// common::separator!(pub Arrow = " -> ");
+/// ```
+#[macro_export]
+macro_rules! separator {
+    ($(#[$meta:meta])* $vis:vis $name:ident = $sep:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        $vis struct $name;
+
+        impl $crate::input::Separator for $name {
+            const NAME: &'static str = concat!("\"", $sep, "\"");
+
+            fn find(s: &str) -> Option<std::ops::Range<usize>> {
+                s.find($sep).map(|start| start..start + $sep.len())
+            }
+        }
+    };
+}
+
+crate::separator!(pub CommaSpace = ", ");
+crate::separator!(pub Arrow = " -> ");
+crate::separator!(pub Colon = ":");
+
+/// Runs of whitespace, like the columns of a table padded with spaces.
+#[derive(Debug, Clone, Copy)]
+pub struct Whitespace;
+
+impl Separator for Whitespace {
+    const NAME: &'static str = "whitespace";
+
+    fn find(s: &str) -> Option<Range<usize>> {
+        let start = s.find(char::is_whitespace)?;
+        let len = s[start..].find(|c: char| !c.is_whitespace()).unwrap_or(s.len() - start);
+        Some(start..start + len)
+    }
+}
+
+pub type WhitespaceSeparated<'a, T> = StrSeparated<'a, T, Whitespace>;
+
+/// Values separated by `S` on each line, ignoring empty lines.
+/// Unlike [`CharSeparated`] the values are trimmed.
+pub struct StrSeparated<'a, T: 'a + FromStr, S: Separator> {
+    source: InputSource<'a>,
+    cursor: usize,
+    _t: PhantomData<(T, S)>,
+}
+
+/// Copies `s` into a source of its own, so nesting it in a value parsed for every line allocates per line.
+/// Values on a hot path are cheaper to parse directly from the `&str`.
+impl<'a, T: FromStr, S: Separator> FromStr for StrSeparated<'a, T, S> {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_source(InputSource::from_string(s.to_owned()))
+    }
+}
+
+impl<'a, T: 'a + FromStr, S: Separator> Input<'a> for StrSeparated<'a, T, S> {
+    type Error = Infallible;
+
+    fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(source: InputSource<'a>) -> Result<Self, Self::Error> {
+        let cursor = source.current_line().len();
+        Ok(Self { source, cursor, _t: PhantomData })
+    }
+}
+
+impl<'a, T: 'a + FromStr, S: Separator> Iterator for StrSeparated<'a, T, S> {
+    type Item = Result<T, InputError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.source.current_line().trim_end();
+            let rest = &line[self.cursor.min(line.len())..];
+            self.cursor += rest.len() - rest.trim_start().len();
+            if self.cursor < line.len() {
+                break;
+            }
+
+            self.cursor = 0;
+            if let Err(e) = self.source.next_line()? {
+                return Some(Err(e));
+            }
+        }
+
+        let line = self.source.current_line().trim_end();
+        let rest = &line[self.cursor..];
+        let (end, next) = S::find(rest).map_or((rest.len(), rest.len()), |sep| (sep.start, sep.end));
+        let start = self.cursor;
+        self.cursor += next;
+
+        let value = line[start..start + end].trim();
+        Some(parse_located(self.source.line(), line, value))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyValueError<K, V> {
+    #[error("missing separator {0}")]
+    MissingSeparator(&'static str),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("invalid key: {0}")]
+    Key(K),
+    #[error("invalid value: {0}")]
+    Value(V),
+}
+
+/// A key and a value split at the first `S`, like `Card 1: 41 48 | 83 86` or `a -> b`.
+/// Both parts are trimmed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValue<K, V, S = Colon> {
+    pub key: K,
+    pub value: V,
+    _s: PhantomData<S>,
+}
+
+impl<K, V, S> KeyValue<K, V, S> {
+    pub fn into_inner(self) -> (K, V) {
+        (self.key, self.value)
+    }
+}
+
+impl<K: FromStr, V: FromStr, S: Separator> FromStr for KeyValue<K, V, S> {
+    type Err = KeyValueError<K::Err, V::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sep = S::find(s).ok_or(KeyValueError::MissingSeparator(S::NAME))?;
+        let key = s[..sep.start].trim().parse().map_err(KeyValueError::Key)?;
+        let value = s[sep.end..].trim().parse().map_err(KeyValueError::Value)?;
+        Ok(Self { key, value, _s: PhantomData })
+    }
+}
+
+/// The whole input as a single pair.
+impl<K, V, S> Input<'_> for KeyValue<K, V, S>
+where
+    K: FromStr,
+    V: FromStr,
+    S: Separator,
+    KeyValueError<K::Err, V::Err>: 'static + Error,
+{
+    type Error = KeyValueError<K::Err, V::Err>;
+
+    fn parse<R: BufRead>(mut read: R) -> Result<Self, Self::Error> {
+        let mut s = String::new();
+        read.read_to_string(&mut s)?;
+        s.parse()
+    }
+}
+
+/// An error parsing one of the sections of a [`LineSeparated`] input.
+/// The positions reported by the section itself are relative to its first line.
 #[derive(Debug)]
-pub struct LineSeparatedError(Box<dyn Error>);
+pub struct LineSeparatedError {
+    /// The first line of the section, starting at 1.
+    pub line: usize,
+    pub source: Box<dyn Error>,
+}
 
 impl Display for LineSeparatedError{
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Error in LineSeparated: {}", self.0)
+        write!(f, "Error in the section starting at line {}: {}", self.line, self.source)
     }
 }
 
 impl Error for LineSeparatedError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(self.0.as_ref())
+        Some(self.source.as_ref())
     }
 }
 
-pub struct LineSeparated<'a, A: Input<'static>, B: 'a + Input<'a>>(A, B, PhantomData<&'a ()>);
+pub struct LineSeparated<'a, A: Input<'a>, B: Input<'a>>(A, B, PhantomData<&'a ()>);
 
-impl<'a, A: Input<'static>, B: 'a + Input<'a>> LineSeparated<'a, A, B> {
+impl<'a, A: Input<'a>, B: Input<'a>> LineSeparated<'a, A, B> {
     pub fn into_inner(self) -> (A, B) {
         (self.0, self.1)
     }
 }
 
-impl<'a, A: Input<'static>, B: 'a + Input<'a>> Input<'a> for LineSeparated<'a, A, B> {
+impl<'a, A: Input<'a>, B: Input<'a>> Input<'a> for LineSeparated<'a, A, B> {
     type Error = LineSeparatedError;
 
-    fn parse<R: 'a + BufRead>(mut read: R) -> Result<Self, Self::Error> {
-        let mut buf = String::new();
-        loop {
-            let len = read.read_line(&mut buf).unwrap();
-            let prev = buf.len() - len;
-            let new = &buf[prev..];
-            if new.chars().all(|c| c.is_ascii_whitespace()) {
-                buf.truncate(prev);
-                break;
-            }
-        }
+    fn parse<R: 'a + BufRead>(read: R) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_reader(read))
+    }
+
+    fn parse_bytes(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse_source(InputSource::from_bytes(bytes))
+    }
+
+    fn parse_source(mut source: InputSource<'a>) -> Result<Self, Self::Error> {
+        let first = source.split_section::<Infallible>()
+            .map_err(|e| LineSeparatedError { line: e.line, source: Box::new(e) })?;
+        let line = source.line() + 1;
 
-        let a = A::parse(Cursor::new(buf.into_bytes()))
-            .map_err(|e| LineSeparatedError(Box::new(e)))?;
-        let b = B::parse(read)
-            .map_err(|e| LineSeparatedError(Box::new(e)))?;
+        let a = A::parse_source(first)
+            .map_err(|e| LineSeparatedError { line: 1, source: Box::new(e) })?;
+        let b = B::parse_source(source)
+            .map_err(|e| LineSeparatedError { line, source: Box::new(e) })?;
 
         Ok(Self(a, b, PhantomData))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn str_separated() {
+        let values = StrSeparated::<u32, CommaSpace>::parse(&b"1, 2, 3\n\n 4 , 5\n"[..]).unwrap();
+        assert_eq!(values.map(Result::unwrap).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+        let table = WhitespaceSeparated::<u32>::from_str("  7  15   30 ").unwrap();
+        assert_eq!(table.map(Result::unwrap).collect::<Vec<_>>(), [7, 15, 30]);
+
+        let err = WhitespaceSeparated::<u32>::parse(&b"1 2\n3  x"[..]).unwrap().find_map(Result::err).unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn line_separated() {
+        let input = LineSeparated::<&str, CommaSeparated<u32>>::parse_bytes(b"a\nb\n\n1,2").unwrap();
+        let (head, values) = input.into_inner();
+        assert_eq!(head, "a\nb");
+        assert_eq!(values.map(Result::unwrap).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn after_header() {
+        let input = crate::input::Headed::<String, CommaSeparated<u32>>::parse_bytes(b"hdr\n1,2").unwrap();
+        assert_eq!(input.header, "hdr");
+        assert_eq!(input.body.map(Result::unwrap).collect::<Vec<_>>(), [1, 2]);
+
+        let input = crate::input::Headed::<String, WhitespaceSeparated<u32>>::parse(&b"hdr\n 1  2\n"[..]).unwrap();
+        assert_eq!(input.body.map(Result::unwrap).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn key_value() {
+        let pair = "a -> bc".parse::<KeyValue<char, String, Arrow>>().unwrap();
+        assert_eq!(pair.into_inner(), ('a', "bc".to_owned()));
+
+        let pair = KeyValue::<String, WhitespaceSeparated<u32>>::parse(&b"Time:   7  15\n"[..]).unwrap();
+        assert_eq!(pair.key, "Time");
+        assert_eq!(pair.value.map(Result::unwrap).collect::<Vec<_>>(), [7, 15]);
+
+        let missing = "a b".parse::<KeyValue<String, String, Arrow>>();
+        assert!(matches!(missing, Err(KeyValueError::MissingSeparator("\" -> \""))));
+    }
+}
@@ -0,0 +1,258 @@
+//! The input shared by the adapters, either streamed from a reader or held in memory.
+
+use std::io::{BufRead, Cursor, Read};
+
+use super::InputError;
+
+enum Inner<'a> {
+    Stream {
+        read: Box<dyn 'a + BufRead>,
+        current: String,
+        peeked: Option<String>,
+    },
+    Buffer {
+        all: &'a [u8],
+        rest: &'a [u8],
+        current: &'a str,
+    },
+}
+
+/// Lines read from a stream or from the complete input held in memory.
+///
+/// Held in memory the lines are borrowed without copying them, the source can be rewound
+/// and [split](InputSource::split_section) into sections borrowing from the same buffer.
+/// Reading through [`BufRead`] skips the line counting.
+pub struct InputSource<'a> {
+    inner: Inner<'a>,
+    line: usize,
+}
+
+impl<'a> InputSource<'a> {
+    pub fn from_reader(read: impl 'a + BufRead) -> Self {
+        let inner = Inner::Stream { read: Box::new(read), current: String::with_capacity(256), peeked: None };
+        Self { inner, line: 0 }
+    }
+
+    pub fn from_bytes(bytes: &'a [u8]) -> Self {
+        Self { inner: Inner::Buffer { all: bytes, rest: bytes, current: "" }, line: 0 }
+    }
+
+    /// A source reading `s` from the start.
+    /// It is streamed like any other reader, so it can't be rewound and its lines are copied.
+    pub fn from_string(s: String) -> Self {
+        Self::from_reader(Cursor::new(s.into_bytes()))
+    }
+
+    pub fn is_buffered(&self) -> bool {
+        matches!(self.inner, Inner::Buffer { .. })
+    }
+
+    /// The number of the last line returned by [`InputSource::next_line`], starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The last line returned by [`InputSource::next_line`] including its line break.
+    pub fn current_line(&self) -> &str {
+        match &self.inner {
+            Inner::Stream { current, .. } => current,
+            Inner::Buffer { current, .. } => current,
+        }
+    }
+
+    /// Reads the next line including its line break.
+    /// Returns [`None`] at the end of the input so adapters can use `?` in [`Iterator::next`].
+    pub fn next_line<E>(&mut self) -> Option<Result<&str, InputError<E>>> {
+        let number = self.line + 1;
+        match &mut self.inner {
+            Inner::Stream { read, current, peeked } => {
+                current.clear();
+                match peeked.take() {
+                    Some(line) => *current = line,
+                    None => match read.read_line(current) {
+                        Ok(0) => return None,
+                        Ok(_) => {}
+                        Err(e) => {
+                            self.line = number;
+                            return Some(Err(InputError::io(number, e)));
+                        }
+                    },
+                }
+            }
+            Inner::Buffer { rest, current, .. } => {
+                let (line, tail) = split_line(rest)?;
+                *rest = tail;
+                *current = match std::str::from_utf8(line) {
+                    Ok(line) => line,
+                    Err(e) => {
+                        self.line = number;
+                        *current = "";
+                        return Some(Err(InputError::io(number, std::io::Error::new(std::io::ErrorKind::InvalidData, e))));
+                    }
+                };
+            }
+        }
+        self.line = number;
+        Some(Ok(self.current_line()))
+    }
+
+    /// The line [`InputSource::next_line`] will return next, without counting it.
+    pub fn peek_line<E>(&mut self) -> Option<Result<&str, InputError<E>>> {
+        let number = self.line + 1;
+        let invalid = |e| InputError::io(number, std::io::Error::new(std::io::ErrorKind::InvalidData, e));
+        match &mut self.inner {
+            Inner::Stream { read, peeked, .. } => {
+                if peeked.is_none() {
+                    let mut line = String::new();
+                    match read.read_line(&mut line) {
+                        Ok(0) => return None,
+                        Ok(_) => *peeked = Some(line),
+                        Err(e) => return Some(Err(InputError::io(number, e))),
+                    }
+                }
+                peeked.as_deref().map(Ok)
+            }
+            Inner::Buffer { rest, .. } => {
+                let (line, _) = split_line(rest)?;
+                Some(std::str::from_utf8(line).map_err(invalid))
+            }
+        }
+    }
+
+    /// Starts reading from the beginning again.
+    /// Returns `false` for a stream, which can't go back.
+    #[must_use]
+    pub fn rewind(&mut self) -> bool {
+        match &mut self.inner {
+            Inner::Stream { .. } => false,
+            Inner::Buffer { all, rest, current } => {
+                *rest = all;
+                *current = "";
+                self.line = 0;
+                true
+            }
+        }
+    }
+
+    /// Splits off the lines up to the next blank line, which is skipped.
+    /// The lines of the section are counted from 1 again.
+    pub fn split_section<E>(&mut self) -> Result<InputSource<'a>, InputError<E>> {
+        if let Inner::Buffer { rest, .. } = &self.inner {
+            let start: &'a [u8] = rest;
+            let mut len = 0;
+            while let Some(line) = self.next_line::<E>() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    break;
+                }
+                len += line.len();
+            }
+            return Ok(Self::from_bytes(&start[..len]));
+        }
+
+        let mut section = String::new();
+        while let Some(line) = self.next_line::<E>() {
+            let line = line?;
+            if line.trim().is_empty() {
+                break;
+            }
+            section.push_str(line);
+        }
+        Ok(Self::from_string(section))
+    }
+
+    /// The rest of the input if it is held in memory.
+    pub fn into_bytes(self) -> Result<&'a [u8], Self> {
+        match self.inner {
+            Inner::Buffer { rest, .. } => Ok(rest),
+            Inner::Stream { .. } => Err(self),
+        }
+    }
+}
+
+/// Splits off the first line of `bytes` including its line break, [`None`] if `bytes` is empty.
+fn split_line(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    if bytes.is_empty() {
+        return None;
+    }
+    let len = bytes.iter().position(|&b| b == b'\n').map_or(bytes.len(), |i| i + 1);
+    Some(bytes.split_at(len))
+}
+
+impl Read for InputSource<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl BufRead for InputSource<'_> {
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        match &mut self.inner {
+            Inner::Stream { peeked: Some(line), .. } if !line.is_empty() => Ok(line.as_bytes()),
+            Inner::Stream { read, .. } => read.fill_buf(),
+            Inner::Buffer { rest, .. } => Ok(rest),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match &mut self.inner {
+            Inner::Stream { peeked: Some(line), .. } if !line.is_empty() => {
+                line.drain(..amt);
+            }
+            Inner::Stream { read, .. } => read.consume(amt),
+            Inner::Buffer { rest, .. } => *rest = &rest[amt..],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    const INPUT: &[u8] = b"a\nb\n\nc\n";
+
+    fn lines(mut source: InputSource) -> Vec<String> {
+        let mut lines = Vec::new();
+        while let Some(line) = source.next_line::<Infallible>() {
+            lines.push(line.unwrap().trim_end().to_owned());
+        }
+        lines
+    }
+
+    #[test]
+    fn streamed_and_buffered() {
+        for mut source in [InputSource::from_reader(INPUT), InputSource::from_bytes(INPUT)] {
+            assert_eq!(source.peek_line::<Infallible>().unwrap().unwrap(), "a\n");
+            assert_eq!(source.next_line::<Infallible>().unwrap().unwrap(), "a\n");
+            assert_eq!((source.line(), source.current_line()), (1, "a\n"));
+
+            let mut rest = String::new();
+            source.read_to_string(&mut rest).unwrap();
+            assert_eq!(rest, "b\n\nc\n");
+        }
+    }
+
+    #[test]
+    fn sections() {
+        for mut source in [InputSource::from_reader(INPUT), InputSource::from_bytes(INPUT)] {
+            let buffered = source.is_buffered();
+            let first = source.split_section::<Infallible>().unwrap();
+            assert_eq!(first.is_buffered(), buffered);
+            assert_eq!(source.line(), 3);
+            assert_eq!(lines(first), ["a", "b"]);
+            assert_eq!(source.rewind(), buffered);
+        }
+
+        let streamed = InputSource::from_string("a\n\nb\n".to_owned()).split_section::<Infallible>().unwrap();
+        assert!(!streamed.is_buffered());
+
+        let mut source = InputSource::from_bytes(INPUT);
+        let _ = source.split_section::<Infallible>();
+        assert_eq!(source.into_bytes().ok(), Some(&b"c\n"[..]));
+    }
+}
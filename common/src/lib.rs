@@ -1,13 +1,25 @@
-use std::{io::BufRead, fmt::Debug};
+use std::{any::Any, fmt::Debug, str::FromStr};
+
+// lets derives generating `::common` paths be used inside this crate
+extern crate self as common;
+
+use params::{Param, Params};
 
 pub mod input;
 pub mod pathfinding;
 pub mod iter_ext;
 pub mod debug;
+pub mod discover;
+pub mod fuzz;
+pub mod grid;
 pub mod macros;
 pub mod geometry_2d;
 pub mod num_enum;
 pub mod bit_set;
+pub mod ocr;
+pub mod params;
+pub mod plugin;
+pub mod viz;
 
 #[derive(Debug)]
 pub struct Year {
@@ -15,32 +27,170 @@ pub struct Year {
     pub days: &'static [Day],
 }
 
+impl Year {
+    pub fn day(&self, name: &str) -> Option<&'static Day> {
+        self.days.iter().find(|d| d.name == name)
+    }
+}
+
 impl std::fmt::Display for Year {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.name)
     }
 }
 
-#[derive(Debug)]
+/// Categories used to describe and filter puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tag {
+    Grid,
+    Pathfinding,
+    Simulation,
+    Parsing,
+    NumberTheory,
+}
+
+impl Tag {
+    pub const ALL: [Tag; 5] = [Tag::Grid, Tag::Pathfinding, Tag::Simulation, Tag::Parsing, Tag::NumberTheory];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Tag::Grid => "grid",
+            Tag::Pathfinding => "pathfinding",
+            Tag::Simulation => "simulation",
+            Tag::Parsing => "parsing",
+            Tag::NumberTheory => "number-theory",
+        }
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("unknown tag '{0}', expected one of grid, pathfinding, simulation, parsing, number-theory")]
+pub struct UnknownTag(String);
+
+impl FromStr for Tag {
+    type Err = UnknownTag;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Tag::ALL
+            .into_iter()
+            .find(|t| t.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| UnknownTag(s.to_owned()))
+    }
+}
+
+/// Optional descriptive information attached to a day or task in [`decl_year!`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Meta {
+    /// The puzzle title.
+    pub title: Option<&'static str>,
+    pub tags: &'static [Tag],
+    /// Whether running takes noticeably longer than a second.
+    pub slow: bool,
+}
+
+/// The output of the parse step shared by the tasks of a day.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+type ParseFn = dyn Sync + Fn(&[u8]) -> Result<Parsed, String>;
+
+#[derive(Clone)]
 pub struct Day {
     pub name: &'static str,
+    pub meta: Meta,
+    pub parse: Option<&'static ParseFn>,
     pub tasks: &'static [Task],
 }
+
+impl Day {
+    pub fn task(&self, name: &str) -> Option<&'static Task> {
+        self.tasks.iter().find(|t| t.name == name)
+    }
+
+    /// Runs the parse step shared by the tasks of the day.
+    /// Returns [`None`] if every task parses the input itself.
+    pub fn parse(&self, input: &[u8]) -> Option<Result<Parsed, String>> {
+        self.parse.map(|parse| parse(input))
+    }
+
+    /// The tags of the day combined with the tags of `task`.
+    pub fn tags_of<'a>(&'a self, task: &'a Task) -> impl Iterator<Item = Tag> + 'a {
+        self.meta.tags.iter().chain(task.meta.tags.iter().filter(|t| !self.meta.tags.contains(t))).copied()
+    }
+
+    /// Whether `task` is marked as slow, either directly or through its day.
+    pub fn is_slow(&self, task: &Task) -> bool {
+        self.meta.slow || task.meta.slow
+    }
+}
+impl Debug for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Day")
+            .field("name", &self.name)
+            .field("meta", &self.meta)
+            .field("tasks", &self.tasks)
+            .finish()
+    }
+}
 impl std::fmt::Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.name)
     }
 }
 
-type TaskFn = dyn Sync + Fn(&mut dyn BufRead) -> Result<String, String>;
+type TaskFn = dyn Sync + Fn(&[u8], &Params) -> Result<String, String>;
+type SolveFn = dyn Sync + Fn(&(dyn Any + Send + Sync), &Params) -> Result<String, String>;
+type VizFn = dyn Sync + Fn(&[u8], &Params, &mut viz::Recorder) -> Result<String, String>;
 pub struct Task {
     pub module: &'static str,
     pub name: &'static str,
     pub func: &'static TaskFn,
+    pub solve: Option<&'static SolveFn>,
+    pub viz: Option<&'static VizFn>,
+    pub meta: Meta,
+    /// The parameters the task accepts together with their defaults.
+    pub params: &'static [Param],
+    /// The values of the parameters matching the example input of the puzzle.
+    pub example: &'static [Param],
+    /// Other implementations of the task which are expected to give the same answers.
+    pub alternatives: &'static [Implementation],
 }
 impl Task {
-    pub fn run(&self, input: &mut impl BufRead) -> Result<String, String> {
-        (self.func)(input)
+    /// The default values of the parameters of the task.
+    pub fn defaults(&self) -> Params {
+        Params::defaults(self.params)
+    }
+
+    /// The parameters of the task for the example input, falling back to the defaults for undeclared values.
+    pub fn example_params(&self) -> Params {
+        let mut params = self.defaults();
+        for p in self.example {
+            // the macro only accepts example values for declared parameters
+            let _ = params.set(p.name, p.default);
+        }
+        params
+    }
+
+    /// Runs the task on the complete input, which the task may borrow from while parsing.
+    /// This includes the shared parse step of the day if there is one.
+    pub fn run(&self, input: &[u8], params: &Params) -> Result<String, String> {
+        (self.func)(input, params)
+    }
+
+    /// Runs the task on the output of the shared parse step of its day.
+    /// Returns [`None`] if the task parses the input itself.
+    pub fn solve(&self, parsed: &Parsed, params: &Params) -> Option<Result<String, String>> {
+        self.solve.map(|solve| solve(parsed.as_ref(), params))
+    }
+
+    /// Runs the visualizing variant of the task recording its frames into `recorder`.
+    /// Returns [`None`] if the task has no visualization.
+    pub fn visualize(&self, input: &[u8], params: &Params, recorder: &mut viz::Recorder) -> Option<Result<String, String>> {
+        self.viz.map(|viz| viz(input, params, recorder))
     }
 }
 impl Debug for Task {
@@ -48,6 +198,9 @@ impl Debug for Task {
         f.debug_struct("Task")
             .field("module", &self.module)
             .field("name", &self.name)
+            .field("meta", &self.meta)
+            .field("params", &self.params)
+            .field("alternatives", &self.alternatives)
             .finish()
     }
 }
@@ -57,20 +210,67 @@ impl std::fmt::Display for Task {
     }
 }
 
+/// An alternative implementation of a task registered with `impls = [...]` in [`decl_year!`].
+/// It takes the same input and parameters as the task itself.
+pub struct Implementation {
+    pub name: &'static str,
+    pub func: &'static TaskFn,
+    pub solve: Option<&'static SolveFn>,
+}
+impl Implementation {
+    /// Runs the implementation on the complete input, see [`Task::run`].
+    pub fn run(&self, input: &[u8], params: &Params) -> Result<String, String> {
+        (self.func)(input, params)
+    }
+
+    /// Runs the implementation on the output of the shared parse step of its day, see [`Task::solve`].
+    pub fn solve(&self, parsed: &Parsed, params: &Params) -> Option<Result<String, String>> {
+        self.solve.map(|solve| solve(parsed.as_ref(), params))
+    }
+}
+impl Debug for Implementation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Implementation").field("name", &self.name).finish()
+    }
+}
+
+/// Declares the day modules of a year and the `YEAR` registry listing their tasks.
+///
+/// Days and tasks accept an optional list of metadata in brackets:
+/// `title = "..."`, `tags = [Grid, Simulation]` and `slow`.
+/// Tasks additionally accept `viz = fn_name` naming their visualizing variant
+/// and `params = [name = value, ...]` declaring [parameters](params) with their defaults,
+/// which are passed as `&Params` after the input (and before the recorder of the visualization).
+/// `example = [name = value, ...]` gives the values of the parameters matching the example input.
+/// `impls = [fn_name, ...]` registers alternative implementations of a task with the same signature.
+/// Days accept `parse = fn_name` naming a parse step shared by all tasks of the day,
+/// which then take a reference to its output instead of parsing the input themselves.
+/// The location of a day module can be overridden with `#[path("...")]`, which is needed when invoked from generated code.
+///
+/// ```ignore
+/// common::decl_year! {
+///     day14 [title = "Regolith Reservoir", tags = [Grid, Simulation]] {task1 [viz = task1_viz]; task2 [slow];}
+///     day15 {task1 [params = [row = 2000000], example = [row = 10]]; task2;}
+///     day07 {task1; task2 [impls = [task2_backwards]];}
+///     day16 [parse = parse] {task1; task2;}
+/// }
+/// ```
 #[macro_export]
 macro_rules! decl_year {
     (
         $(
             $(#[doc($path:literal)])?
-            $day:ident {
+            $(#[path($file:literal)])?
+            $day:ident $([$($day_meta:tt)*])? {
                 $(
-                    $task:ident;
+                    $task:ident $([$($task_meta:tt)*])?;
                 )*
             }
         )*
     ) => {
         $(
             $(#[doc = include_str!($path)])?
+            $(#[path = $file])?
             pub mod $day;
         )*
 
@@ -78,25 +278,291 @@ macro_rules! decl_year {
         pub static YEAR: $crate::Year = $crate::Year {
             name: module_path!(),
             days: &[
-                $($crate::Day {
-                    name: stringify!($day),
-                    tasks: &[
-                        $($crate::Task {
-                            module: module_path!(),
-                            name: stringify!($task),
-                            func: & |mut read| {
-                                match $crate::input::Input::parse(&mut read) {
-                                    Ok(input) => match $day :: $task (input) {
-                                        Ok(res) => Ok(format!("{}", res)),
-                                        Err(err) => Err(format!("{}", err)),
-                                    },
-                                    Err(err) => Err(format!("{}", err)),
-                                }
-                            }
-                        },)*
-                    ]
-                },)*
+                $($crate::__decl_day!($day [$($($day_meta)*)?] { $($task [$($($task_meta)*)?];)* }),)*
             ]
         };
     };
-}
\ No newline at end of file
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_day {
+    ($day:ident $day_meta:tt { $($task:ident $task_meta:tt;)* }) => {
+        $crate::Day {
+            name: stringify!($day),
+            meta: $crate::__decl_meta!($day_meta),
+            parse: $crate::__decl_parse!($day $day_meta),
+            tasks: &[
+                $($crate::Task {
+                    module: module_path!(),
+                    name: stringify!($task),
+                    func: $crate::__decl_func!($day $task $task_meta $day_meta),
+                    solve: $crate::__decl_solve!($day $task $task_meta $day_meta),
+                    viz: $crate::__decl_viz!($day $task_meta),
+                    meta: $crate::__decl_meta!($task_meta),
+                    params: $crate::__decl_params!(params $task_meta),
+                    example: $crate::__decl_params!(example $task_meta),
+                    alternatives: $crate::__decl_alternatives!($day $task_meta $day_meta),
+                },)*
+            ]
+        }
+    };
+}
+
+/// Formats the result of a task for the runners.
+#[doc(hidden)]
+pub fn __format<T: std::fmt::Display, E: std::fmt::Display>(result: Result<T, E>) -> Result<String, String> {
+    match result {
+        Ok(res) => Ok(format!("{}", res)),
+        Err(err) => Err(format!("{}", err)),
+    }
+}
+
+/// Runs the shared parse step of a day boxing its output.
+#[doc(hidden)]
+pub fn __parse<'a, I, P, E>(bytes: &'a [u8], parse: fn(I) -> Result<P, E>) -> Result<Parsed, String>
+where
+    I: input::Input<'a>,
+    P: Any + Send + Sync,
+    E: std::fmt::Display,
+{
+    let input = I::parse_bytes(bytes).map_err(|e| format!("{}", e))?;
+    match parse(input) {
+        Ok(parsed) => Ok(Box::new(parsed)),
+        Err(err) => Err(format!("{}", err)),
+    }
+}
+
+/// Runs a task on the output of the shared parse step of its day.
+#[doc(hidden)]
+pub fn __solve<P, T, E>(parsed: &(dyn Any + Send + Sync), task: impl FnOnce(&P) -> Result<T, E>) -> Result<String, String>
+where
+    P: Any,
+    T: std::fmt::Display,
+    E: std::fmt::Display,
+{
+    match parsed.downcast_ref::<P>() {
+        Some(parsed) => __format(task(parsed)),
+        None => Err("The parse step returned an unexpected type".to_owned()),
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_func {
+    ($day:ident $task:ident $task_meta:tt [$($meta:tt)*]) => {
+        $crate::__decl_func!(@ $day $task $task_meta; $($meta)*)
+    };
+    (@ $day:ident $task:ident $task_meta:tt;) => {
+        & |bytes, params| {
+            match $crate::input::Input::parse_bytes(bytes) {
+                Ok(input) => $crate::__format($crate::__decl_call!($task_meta $day :: $task, input, params)),
+                Err(err) => Err(format!("{}", err)),
+            }
+        }
+    };
+    (@ $day:ident $task:ident $task_meta:tt; parse = $parse:ident $(, $($rest:tt)*)?) => {
+        & |bytes, params| {
+            let parsed = $crate::__parse(bytes, $day :: $parse)?;
+            $crate::__solve(parsed.as_ref(), |p| $crate::__decl_call!($task_meta $day :: $task, p, params))
+        }
+    };
+    (@ $day:ident $task:ident $task_meta:tt; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_func!(@ $day $task $task_meta; $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_solve {
+    ($day:ident $task:ident $task_meta:tt [$($meta:tt)*]) => {
+        $crate::__decl_solve!(@ $day $task $task_meta; $($meta)*)
+    };
+    (@ $day:ident $task:ident $task_meta:tt;) => {
+        None
+    };
+    (@ $day:ident $task:ident $task_meta:tt; parse = $parse:ident $(, $($rest:tt)*)?) => {
+        Some(& |parsed, params| $crate::__solve(parsed, |p| $crate::__decl_call!($task_meta $day :: $task, p, params)))
+    };
+    (@ $day:ident $task:ident $task_meta:tt; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_solve!(@ $day $task $task_meta; $($($rest)*)?)
+    };
+}
+
+/// Calls a task function passing the parameters only if the task declares any.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_call {
+    ([$($meta:tt)*] $func:path, $input:expr, $params:expr $(, $extra:expr)?) => {
+        $crate::__decl_call!(@ $func, $input, $params $(, $extra)?; $($meta)*)
+    };
+    (@ $func:path, $input:expr, $params:expr $(, $extra:expr)?;) => {{
+        let _ = $params;
+        $func($input $(, $extra)?)
+    }};
+    (@ $func:path, $input:expr, $params:expr $(, $extra:expr)?; params = $value:tt $(, $($rest:tt)*)?) => {
+        $func($input, $params $(, $extra)?)
+    };
+    (@ $func:path, $input:expr, $params:expr $(, $extra:expr)?; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_call!(@ $func, $input, $params $(, $extra)?; $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_alternatives {
+    ($day:ident [$($meta:tt)*] $day_meta:tt) => {
+        $crate::__decl_alternatives!(@ $day [$($meta)*] $day_meta; $($meta)*)
+    };
+    (@ $day:ident $task_meta:tt $day_meta:tt;) => {
+        &[]
+    };
+    (@ $day:ident $task_meta:tt $day_meta:tt; impls = [$($alt:ident),* $(,)?] $(, $($rest:tt)*)?) => {
+        &[$($crate::Implementation {
+            name: stringify!($alt),
+            func: $crate::__decl_func!($day $alt $task_meta $day_meta),
+            solve: $crate::__decl_solve!($day $alt $task_meta $day_meta),
+        }),*]
+    };
+    (@ $day:ident $task_meta:tt $day_meta:tt; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_alternatives!(@ $day $task_meta $day_meta; $($($rest)*)?)
+    };
+}
+
+/// Like [`__decl_meta!`] this rejects unknown keys:
+///
+/// ```
+/// let params: &[common::params::Param] = common::__decl_params!(params [slow, params = [size = 71], example = [size = 7]]);
+/// assert_eq!(params[0].default, "71");
+/// ```
+///
+/// ```compile_fail
+/// let params: &[common::params::Param] = common::__decl_params!(params [paramz = [size = 71]]);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_params {
+    ($kind:ident [$($meta:tt)*]) => {
+        $crate::__decl_params!(@ $kind; $($meta)*)
+    };
+    (@ $kind:ident;) => {
+        &[]
+    };
+    (@ params; params = [$($name:ident = $value:literal),* $(,)?] $(, $($rest:tt)*)?) => {
+        &[$($crate::params::Param { name: stringify!($name), default: stringify!($value) }),*]
+    };
+    (@ example; example = [$($name:ident = $value:literal),* $(,)?] $(, $($rest:tt)*)?) => {
+        &[$($crate::params::Param { name: stringify!($name), default: stringify!($value) }),*]
+    };
+    (@ $kind:ident; params = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; example = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; title = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; tags = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; viz = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; impls = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; slow $(, $($rest:tt)*)?) => {
+        $crate::__decl_params!(@ $kind; $($($rest)*)?)
+    };
+    (@ $kind:ident; $key:ident $($rest:tt)*) => {
+        compile_error!(concat!("unknown task metadata `", stringify!($key), "`"))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_parse {
+    ($day:ident [$($meta:tt)*]) => {
+        $crate::__decl_parse!(@ $day; $($meta)*)
+    };
+    (@ $day:ident;) => {
+        None
+    };
+    (@ $day:ident; parse = $parse:ident $(, $($rest:tt)*)?) => {
+        Some(& |bytes| $crate::__parse(bytes, $day :: $parse))
+    };
+    (@ $day:ident; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_parse!(@ $day; $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_viz {
+    ($day:ident [$($meta:tt)*]) => {
+        $crate::__decl_viz!(@ $day [$($meta)*]; $($meta)*)
+    };
+    (@ $day:ident $task_meta:tt;) => {
+        None
+    };
+    (@ $day:ident $task_meta:tt; viz = $viz:ident $(, $($rest:tt)*)?) => {
+        Some(& |bytes, params, recorder| {
+            match $crate::input::Input::parse_bytes(bytes) {
+                Ok(input) => $crate::__format($crate::__decl_call!($task_meta $day :: $viz, input, params, recorder)),
+                Err(err) => Err(format!("{}", err)),
+            }
+        })
+    };
+    (@ $day:ident $task_meta:tt; $key:ident $(= $value:tt)? $(, $($rest:tt)*)?) => {
+        $crate::__decl_viz!(@ $day $task_meta; $($($rest)*)?)
+    };
+}
+
+/// Keys other than the ones documented for [`decl_year!`] are rejected, so a typo isn't dropped silently:
+///
+/// ```
+/// let meta = common::__decl_meta!([title = "Example", viz = task1_viz, params = [size = 7]]);
+/// assert_eq!(meta.title, Some("Example"));
+/// ```
+///
+/// ```compile_fail
+/// let meta = common::__decl_meta!([titel = "Example"]);
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decl_meta {
+    ([$($meta:tt)*]) => {
+        $crate::__decl_meta!(@ (None) ([]) (false); $($meta)*)
+    };
+    (@ $title:tt $tags:tt $slow:tt;) => {
+        $crate::Meta { title: $title, tags: &$tags, slow: $slow }
+    };
+    (@ $title:tt $tags:tt $slow:tt; title = $value:literal $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ (Some($value)) $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; tags = [$($tag:ident),* $(,)?] $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title ([$($crate::Tag::$tag),*]) $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; slow $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags (true); $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; params = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; example = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; viz = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; impls = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; parse = $value:tt $(, $($rest:tt)*)?) => {
+        $crate::__decl_meta!(@ $title $tags $slow; $($($rest)*)?)
+    };
+    (@ $title:tt $tags:tt $slow:tt; $key:ident $($rest:tt)*) => {
+        compile_error!(concat!("unknown metadata `", stringify!($key), "`"))
+    };
+}
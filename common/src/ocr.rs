@@ -0,0 +1,210 @@
+//! Decoding of the block letter pictures some puzzles produce as their answer.
+//!
+//! Two fonts are supported and selected by the height of the picture:
+//! the common 4x6 font and the larger 6x10 font.
+//! Glyphs are told apart by the blank columns between them, so they may differ in width,
+//! like the 3 pixel wide `I` and the 5 pixel wide `Y` of the small font.
+
+use crate::geometry_2d::Point;
+
+#[derive(Debug, thiserror::Error)]
+pub enum OcrError {
+    #[error("picture is empty")]
+    Empty,
+    #[error("no font has a height of {0} pixels")]
+    UnsupportedHeight(usize),
+    #[error("unknown glyph at column {column}:\n{glyph}")]
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+/// The standard 4x6 block letter font. Each glyph is stored row by row, `I` and `Y` are 3 and 5 pixels wide.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', "###.#..#..#..#.###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...##...#.#.#...#....#....#.."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// The large 6x10 block letter font. Each glyph is stored row by row.
+const LARGE: &[(char, &str)] = &[
+    ('A', "..##...#..#.#....##....##....########....##....##....##....#"),
+    ('B', "#####.#....##....##....######.#....##....##....##....######."),
+    ('C', ".####.#....##.....#.....#.....#.....#.....#.....#....#.####."),
+    ('E', "#######.....#.....#.....#####.#.....#.....#.....#.....######"),
+    ('F', "#######.....#.....#.....#####.#.....#.....#.....#.....#....."),
+    ('G', ".####.#....##.....#.....#.....#..####....##....##...##.###.#"),
+    ('H', "#....##....##....##....########....##....##....##....##....#"),
+    ('J', "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###.."),
+    ('K', "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#"),
+    ('L', "#.....#.....#.....#.....#.....#.....#.....#.....#.....######"),
+    ('N', "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#"),
+    ('P', "#####.#....##....##....######.#.....#.....#.....#.....#....."),
+    ('R', "#####.#....##....##....######.#..#..#...#.#...#.#....##....#"),
+    ('X', "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#"),
+    ('Z', "######.....#.....#....#....#....#....#....#.....#.....######"),
+];
+
+/// Decodes a picture of `width` x `height` pixels where `lit(x, y)` reports whether a pixel is set.
+///
+/// Blank rows above and below the text are ignored and glyphs are separated by blank columns.
+pub fn decode(width: usize, height: usize, lit: impl Fn(usize, usize) -> bool) -> Result<String, OcrError> {
+    let blank_row = |y: usize| (0..width).all(|x| !lit(x, y));
+    let top = (0..height).find(|y| !blank_row(*y)).ok_or(OcrError::Empty)?;
+    let bottom = (0..height).rev().find(|y| !blank_row(*y)).ok_or(OcrError::Empty)? + 1;
+
+    let font = match bottom - top {
+        6 => SMALL,
+        10 => LARGE,
+        h => return Err(OcrError::UnsupportedHeight(h)),
+    };
+
+    let blank_column = |x: usize| (top..bottom).all(|y| !lit(x, y));
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !blank_column(x) {
+            x += 1;
+        }
+
+        let glyph = (top..bottom)
+            .flat_map(|y| (start..x).map(move |x| (x, y)))
+            .map(|(x, y)| if lit(x, y) { '#' } else { '.' })
+            .collect::<String>();
+        match font.iter().find(|(_, g)| *g == glyph) {
+            Some((c, _)) => text.push(*c),
+            None => {
+                let rows = glyph.as_bytes().chunks(x - start);
+                let glyph = rows.map(|r| std::str::from_utf8(r).unwrap()).collect::<Vec<_>>().join("\n");
+                return Err(OcrError::UnknownGlyph { column: start, glyph });
+            }
+        }
+    }
+    Ok(text)
+}
+
+/// Decodes a picture given as text where `#` and `█` mark lit pixels.
+pub fn decode_str(picture: &str) -> Result<String, OcrError> {
+    let rows = picture
+        .lines()
+        .map(|l| l.chars().map(|c| c == '#' || c == '█').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    decode(width, rows.len(), |x, y| rows[y].get(x).copied().unwrap_or(false))
+}
+
+/// Decodes a picture given as the set of lit pixels. The picture is aligned to the top left most point.
+pub fn decode_points(points: &[Point]) -> Result<String, OcrError> {
+    let min_x = points.iter().map(|p| p.x).min().ok_or(OcrError::Empty)?;
+    let min_y = points.iter().map(|p| p.y).min().ok_or(OcrError::Empty)?;
+    let max_x = points.iter().map(|p| p.x).max().ok_or(OcrError::Empty)?;
+    let max_y = points.iter().map(|p| p.y).max().ok_or(OcrError::Empty)?;
+
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut pixels = vec![false; width * height];
+    for p in points {
+        pixels[(p.y - min_y) as usize * width + (p.x - min_x) as usize] = true;
+    }
+    decode(width, height, |x, y| pixels[y * width + x])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_small() {
+        let picture = "
+#..#.####.###..###.
+#..#.#....#..#.#..#
+####.###..#..#.#..#
+#..#.#....###..###.
+#..#.#....#....#.#.
+#..#.####.#....#..#
+";
+        assert_eq!(decode_str(picture).unwrap(), "HEPR");
+    }
+
+    #[test]
+    fn decode_narrow_and_wide() {
+        let picture = "
+###.#...#
+.#..#...#
+.#...#.#.
+.#....#..
+.#....#..
+###...#..
+";
+        assert_eq!(decode_str(picture).unwrap(), "IY");
+    }
+
+    #[test]
+    fn decode_large() {
+        let picture = "\
+#....#..######
+#....#.......#
+#....#.......#
+#....#......#.
+######.....#..
+#....#....#...
+#....#...#....
+#....#..#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(decode_str(picture).unwrap(), "HZ");
+    }
+
+    #[test]
+    fn unknown_glyph_column() {
+        let picture = "
+#..#.#.#
+#..#.#.#
+####.#.#
+#..#.#.#
+#..#.#.#
+#..#.###
+";
+        match decode_str(picture) {
+            Err(OcrError::UnknownGlyph { column, glyph }) => {
+                assert_eq!(column, 5);
+                assert_eq!(glyph.lines().count(), 6);
+            }
+            r => panic!("expected unknown glyph, got {r:?}"),
+        }
+    }
+
+    #[test]
+    fn decode_points_offset() {
+        // the letter L shifted away from the origin
+        let points = (0..6)
+            .map(|y| Point::new(3, 2 + y))
+            .chain((1..4).map(|x| Point::new(3 + x, 7)))
+            .collect::<Vec<_>>();
+        assert_eq!(decode_points(&points).unwrap(), "L");
+    }
+
+    #[test]
+    fn glyphs_have_font_size() {
+        assert!(SMALL.iter().all(|(_, g)| g.len().is_multiple_of(6)));
+        assert!(LARGE.iter().all(|(_, g)| g.len() == 60));
+    }
+}
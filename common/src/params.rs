@@ -0,0 +1,148 @@
+//! Named runtime parameters of tasks, such as the size of a grid that differs between the example and the real input.
+//!
+//! A task declares its parameters and their defaults in [`decl_year!`](crate::decl_year)
+//! (`params = [width = 101, height = 103]`) and optionally the values matching the example of the puzzle
+//! (`example = [width = 11, height = 7]`). It then receives the resolved [`Params`] as its second argument.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A parameter declared by a task together with its default value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParamError {
+    #[error("unknown parameter '{0}'")]
+    Unknown(String),
+    #[error("missing parameter '{0}'")]
+    Missing(String),
+    #[error("invalid value '{value}' for parameter '{name}': {reason}")]
+    Invalid { name: String, value: String, reason: String },
+    #[error("malformed parameter '{0}', expected name=value")]
+    Malformed(String),
+}
+
+/// A single `name=value` pair overriding the default of a parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Override {
+    pub name: String,
+    pub value: String,
+}
+
+impl FromStr for Override {
+    type Err = ParamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => Ok(Self {
+                name: name.trim().to_owned(),
+                value: value.trim().to_owned(),
+            }),
+            _ => Err(ParamError::Malformed(s.to_owned())),
+        }
+    }
+}
+
+/// The values of the parameters passed to a task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    /// The default values of the `declared` parameters.
+    pub fn defaults(declared: &'static [Param]) -> Self {
+        Self(declared.iter().map(|p| (p.name, p.default.to_owned())).collect())
+    }
+
+    /// Replaces the values of the parameters named by `overrides`.
+    /// Fails if an override names a parameter that is not declared.
+    pub fn with_overrides<'a>(mut self, overrides: impl IntoIterator<Item = &'a Override>) -> Result<Self, ParamError> {
+        for o in overrides {
+            self.set(&o.name, &o.value)?;
+        }
+        Ok(self)
+    }
+
+    /// Changes the value of an existing parameter.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError> {
+        match self.0.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => {
+                *v = value.to_owned();
+                Ok(())
+            }
+            None => Err(ParamError::Unknown(name.to_owned())),
+        }
+    }
+
+    /// Parses the value of the parameter `name`.
+    pub fn get<T>(&self, name: &str) -> Result<T, ParamError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (_, value) = self
+            .0
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| ParamError::Missing(name.to_owned()))?;
+        value.parse().map_err(|e: T::Err| ParamError::Invalid {
+            name: name.to_owned(),
+            value: value.clone(),
+            reason: e.to_string(),
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.0.iter().map(|(n, v)| (*n, v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, value)) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[
+        Param { name: "width", default: "101" },
+        Param { name: "height", default: "103" },
+    ];
+
+    #[test]
+    fn overrides() {
+        let overrides = ["width=11".parse::<Override>().unwrap()];
+        let params = Params::defaults(DECLARED).with_overrides(&overrides).unwrap();
+        assert_eq!(params.get::<usize>("width"), Ok(11));
+        assert_eq!(params.get::<usize>("height"), Ok(103));
+        assert_eq!(params.to_string(), "width=11, height=103");
+    }
+
+    #[test]
+    fn errors() {
+        let unknown = ["depth=3".parse::<Override>().unwrap()];
+        assert_eq!(Params::defaults(DECLARED).with_overrides(&unknown), Err(ParamError::Unknown("depth".to_owned())));
+        assert_eq!("width".parse::<Override>(), Err(ParamError::Malformed("width".to_owned())));
+
+        let params = Params::defaults(DECLARED);
+        assert_eq!(params.get::<usize>("depth"), Err(ParamError::Missing("depth".to_owned())));
+        assert!(matches!(params.get::<u8>("width"), Ok(101)));
+        assert!(matches!(params.get::<bool>("width"), Err(ParamError::Invalid { .. })));
+    }
+}
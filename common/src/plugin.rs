@@ -0,0 +1,92 @@
+//! Registration of years compiled into a separate `cdylib`, which the runner loads at runtime with `--plugin`.
+//!
+//! A plugin crate declares its days with [`decl_year!`](crate::decl_year) as usual and exports the result:
+//!
+//! ```ignore
+//! // Cargo.toml: [lib] crate-type = ["cdylib"]
+//! common::decl_year! {
+//!     day01 {task1; task2;}
+//! }
+//! common::export_plugin!(YEAR);
+//! ```
+//!
+//! [`Year`] and its days and tasks are regular Rust types without a stable layout, so a plugin only works
+//! if it was built with the same compiler and the same version of `common` as the runner.
+//! Both are recorded in [`ABI_VERSION`] and compared before the years of a plugin are used.
+
+use crate::Year;
+
+/// Identifies the layout of the registration types, plugins built with a different one are rejected.
+pub const ABI_VERSION: &str = concat!("common ", env!("CARGO_PKG_VERSION"), ", ", env!("AOC_RUSTC_VERSION"));
+
+/// The name of the static exported by [`export_plugin!`](crate::export_plugin).
+pub const SYMBOL: &[u8] = b"AOC_PLUGIN";
+
+/// The years a plugin contributes to the runner.
+/// Only consists of raw pointers so the ABI version can be checked before anything else is touched.
+#[repr(C)]
+pub struct Registration {
+    abi: *const u8,
+    abi_len: usize,
+    years: *const &'static Year,
+    years_len: usize,
+}
+
+// SAFETY: the pointers are created from shared 'static references in `new`
+unsafe impl Sync for Registration {}
+
+impl Registration {
+    pub const fn new(years: &'static [&'static Year]) -> Self {
+        Self {
+            abi: ABI_VERSION.as_ptr(),
+            abi_len: ABI_VERSION.len(),
+            years: years.as_ptr(),
+            years_len: years.len(),
+        }
+    }
+
+    /// The ABI version the plugin was built with.
+    pub fn abi_version(&self) -> String {
+        // SAFETY: created from a &'static str in `new`
+        let bytes = unsafe { std::slice::from_raw_parts(self.abi, self.abi_len) };
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+
+    /// The registered years, or [`None`] if the plugin was built with a different ABI version.
+    pub fn years(&self) -> Option<&'static [&'static Year]> {
+        if self.abi_version() != ABI_VERSION {
+            return None;
+        }
+        // SAFETY: created from a &'static slice in `new` whose layout matches ours
+        Some(unsafe { std::slice::from_raw_parts(self.years, self.years_len) })
+    }
+}
+
+/// Exports the given years as the [`Registration`] of a plugin.
+#[macro_export]
+macro_rules! export_plugin {
+    ($($year:path),+ $(,)?) => {
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static AOC_PLUGIN: $crate::plugin::Registration = {
+            static YEARS: &[&$crate::Year] = &[$(&$year),+];
+            $crate::plugin::Registration::new(YEARS)
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static YEAR: Year = Year { name: "plugin", days: &[] };
+    crate::export_plugin!(YEAR);
+
+    #[test]
+    fn registration() {
+        assert_eq!(AOC_PLUGIN.abi_version(), ABI_VERSION);
+        let years = AOC_PLUGIN.years().unwrap();
+        assert_eq!(years.len(), 1);
+        assert_eq!(years[0].name, "plugin");
+    }
+}
@@ -0,0 +1,224 @@
+//! Hooks for recording the intermediate states of simulations so they can be played back step by step.
+//!
+//! A day implements [`Visualize`] for its state and threads an [`Observer`] through its main loop.
+//! The regular task passes [`NoViz`] whose hooks are empty and get optimized away entirely,
+//! while the visualizing variant registered in [`decl_year!`](crate::decl_year) passes a [`Recorder`].
+
+use std::fmt::Display;
+
+/// A snapshot of a simulation: a grid of chars plus a status line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    cells: Vec<char>,
+    pub status: String,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: char) -> Self {
+        Self {
+            width,
+            cells: vec![fill; width * height],
+            status: String::new(),
+        }
+    }
+
+    /// Creates a frame by evaluating `f(x, y)` for every cell.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> char) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            width,
+            cells,
+            status: String::new(),
+        }
+    }
+
+    pub fn with_status(mut self, status: impl Into<String>) -> Self {
+        self.status = status.into();
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x >= self.width {
+            return None;
+        }
+        self.cells.get(y * self.width + x).copied()
+    }
+
+    /// Sets the cell at `(x, y)`, positions outside of the frame are ignored.
+    pub fn set(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width {
+            if let Some(cell) = self.cells.get_mut(y * self.width + x) {
+                *cell = c;
+            }
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[char]> {
+        self.cells.chunks(self.width.max(1))
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            let line = row.iter().collect::<String>();
+            writeln!(f, "{line}")?;
+        }
+        f.write_str(&self.status)
+    }
+}
+
+/// Implemented by simulation states which can be rendered into a [`Frame`].
+pub trait Visualize {
+    fn frame(&self) -> Frame;
+}
+
+/// Receives the frames emitted from inside a simulation loop.
+///
+/// Frames are passed lazily so an observer which discards them never pays for creating them.
+pub trait Observer {
+    fn frame(&mut self, frame: impl FnOnce() -> Frame);
+
+    fn observe<V: Visualize + ?Sized>(&mut self, state: &V) {
+        self.frame(|| state.frame());
+    }
+}
+
+/// Observer for the regular run path discarding all frames.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoViz;
+
+impl Observer for NoViz {
+    #[inline(always)]
+    fn frame(&mut self, _: impl FnOnce() -> Frame) {}
+
+    #[inline(always)]
+    fn observe<V: Visualize + ?Sized>(&mut self, _: &V) {}
+}
+
+/// Observer collecting frames for later playback.
+///
+/// To bound memory usage the recorder keeps at most `budget` cells in total.
+/// Once exceeded every other recorded frame is dropped and only every second frame is recorded from then on,
+/// so long simulations are still covered from start to end at a coarser resolution.
+#[derive(Debug)]
+pub struct Recorder {
+    frames: Vec<Frame>,
+    cells: usize,
+    budget: usize,
+    stride: usize,
+    seen: usize,
+}
+
+impl Recorder {
+    /// Default number of cells kept by [`Recorder::default`].
+    pub const DEFAULT_BUDGET: usize = 1 << 25;
+
+    pub fn new(budget: usize) -> Self {
+        Self {
+            frames: Vec::new(),
+            cells: 0,
+            budget,
+            stride: 1,
+            seen: 0,
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.frames
+    }
+
+    /// The number of emitted frames each recorded frame stands for.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    fn decimate(&mut self) {
+        let mut index = 0;
+        self.frames.retain(|_| {
+            index += 1;
+            index % 2 == 1
+        });
+        self.cells = self.frames.iter().map(|f| f.cells.len()).sum();
+        self.stride *= 2;
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_BUDGET)
+    }
+}
+
+impl Observer for Recorder {
+    fn frame(&mut self, frame: impl FnOnce() -> Frame) {
+        let seen = self.seen;
+        self.seen += 1;
+        if !seen.is_multiple_of(self.stride) {
+            return;
+        }
+
+        let frame = frame();
+        self.cells += frame.cells.len();
+        self.frames.push(frame);
+        while self.cells > self.budget && self.frames.len() > 1 {
+            self.decimate();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(usize);
+
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            Frame::from_fn(2, 2, |x, y| if x + y * 2 == self.0 % 4 { '#' } else { '.' })
+                .with_status(format!("{}", self.0))
+        }
+    }
+
+    #[test]
+    fn frame_display() {
+        let frame = Counter(1).frame();
+        assert_eq!(frame.to_string(), ".#\n..\n1");
+        assert_eq!(frame.height(), 2);
+        assert_eq!(frame.get(1, 0), Some('#'));
+        assert_eq!(frame.get(2, 0), None);
+    }
+
+    #[test]
+    fn recorder_decimates() {
+        // every frame has 4 cells so 5 frames fit into the budget
+        let mut recorder = Recorder::new(20);
+        for i in 0..12 {
+            recorder.observe(&Counter(i));
+        }
+        assert_eq!(recorder.stride(), 4);
+        let statuses = recorder.frames().iter().map(|f| f.status.as_str()).collect::<Vec<_>>();
+        assert_eq!(statuses, ["0", "4", "8"]);
+    }
+
+    #[test]
+    fn no_viz_is_lazy() {
+        NoViz.frame(|| unreachable!());
+    }
+}
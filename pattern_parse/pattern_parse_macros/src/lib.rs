@@ -54,23 +54,39 @@ impl PartialEq for PatternElement<'_> {
 #[derive(Debug, PartialEq)]
 struct Pattern<'a>(Vec<PatternElement<'a>>);
 
+/// A part of a pattern, either literal text or the contents of a `{}` placeholder.
+#[derive(Debug, PartialEq)]
+enum Segment<'a> {
+    Literal(&'a str),
+    Placeholder(&'a str),
+}
+
+fn segments(mut s: &str) -> Result<Vec<Segment<'_>>, String> {
+    let mut segments = Vec::new();
+    while !s.is_empty() {
+        if let Some(rest) = s.strip_prefix('{') {
+            let end = rest
+                .find('}')
+                .ok_or_else(|| format!("unclosed placeholder in \"{s}\""))?;
+            segments.push(Segment::Placeholder(&rest[..end]));
+            s = &rest[end + 1..];
+        } else {
+            let end = s.find('{').unwrap_or(s.len());
+            segments.push(Segment::Literal(&s[..end]));
+            s = &s[end..];
+        }
+    }
+    Ok(segments)
+}
+
 impl<'a> Pattern<'a> {
-    fn from_str(mut s: &'a str) -> Result<Self, Box<dyn Error>> {
+    fn from_str(s: &'a str) -> Result<Self, Box<dyn Error>> {
         let mut elements = Vec::new();
-        while s.is_empty() == false {
-            let mut chars = s.chars();
-            if chars.next() == Some('{') {
-                let ty_len = chars.take_while(|c| *c != '}').count();
-                let ty = syn::parse_str(&s[1..ty_len + 1])?;
-                elements.push(PatternElement::Parse(ty));
-                s = &s[ty_len + 2..];
-            } else {
-                let lit_len = s.chars().take_while(|c| *c != '{').count();
-                if lit_len > 0 {
-                    elements.push(PatternElement::Literal(&s[..lit_len]));
-                    s = &s[lit_len..];
-                }
-            }
+        for segment in segments(s)? {
+            elements.push(match segment {
+                Segment::Literal(lit) => PatternElement::Literal(lit),
+                Segment::Placeholder(ty) => PatternElement::Parse(syn::parse_str(ty)?),
+            });
         }
         Ok(Self(elements))
     }
@@ -188,6 +204,163 @@ pub fn parse_fn(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
     parse_fn_core(decl).unwrap().to_token_stream().into()
 }
 
+/// How a field of a struct deriving [`PatternParse`] gets its value, set with `#[pattern(...)]`.
+enum FieldAttr {
+    /// `#[pattern(default)]`: not part of the pattern, initialized with [`Default::default`].
+    Default,
+    /// `#[pattern(with = path)]`: parsed by calling `path(&str) -> Result<T, E>` instead of [`FromStr`](std::str::FromStr).
+    With(syn::Path),
+}
+
+impl Parse for FieldAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "default" {
+            Ok(Self::Default)
+        } else if ident == "with" {
+            input.parse::<Token!(=)>()?;
+            Ok(Self::With(input.parse()?))
+        } else {
+            Err(syn::Error::new_spanned(ident, "expected `default` or `with = path`"))
+        }
+    }
+}
+
+fn field_attr(field: &syn::Field) -> syn::Result<Option<FieldAttr>> {
+    field
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("pattern"))
+        .map(|attr| attr.parse_args())
+        .transpose()
+}
+
+fn derive_core(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let syn::Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(name, "PatternParse can only be derived for structs"));
+    };
+    let pattern = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("pattern"))
+        .ok_or_else(|| syn::Error::new_spanned(name, "missing #[pattern(\"...\")] attribute"))?
+        .parse_args::<LitStr>()?;
+    let value = pattern.value();
+    let segments = segments(&value).map_err(|e| syn::Error::new_spanned(&pattern, e))?;
+
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let member = match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(i.into()),
+            };
+            Ok((member, field, field_attr(field)?))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut steps = TokenStream::new();
+    let mut inits = Vec::new();
+    let mut parsed = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        if let Segment::Literal(lit) = segment {
+            quote!(::pattern_parse::derive::literal(input, &mut s, #lit)?;).to_tokens(&mut steps);
+            continue;
+        }
+
+        let until = match segments.get(i + 1) {
+            Some(Segment::Literal(lit)) => quote!(Some(#lit)),
+            Some(Segment::Placeholder(_)) => {
+                return Err(syn::Error::new_spanned(&pattern, "placeholders have to be separated by literal text"))
+            }
+            None => quote!(None),
+        };
+        match segment {
+            Segment::Literal(_) => unreachable!(),
+            Segment::Placeholder("_") => {
+                quote!(::pattern_parse::derive::skip(input, &mut s, #until)?;).to_tokens(&mut steps)
+            }
+            Segment::Placeholder(placeholder) => {
+                let error = || syn::Error::new_spanned(&pattern, format!("no field named `{placeholder}`"));
+                let member = syn::parse_str::<syn::Member>(placeholder).map_err(|_| error())?;
+                let (_, field, attr) = fields.iter().find(|(m, ..)| *m == member).ok_or_else(error)?;
+                if parsed.contains(&member) {
+                    let msg = format!("field `{placeholder}` is used more than once");
+                    return Err(syn::Error::new_spanned(&pattern, msg));
+                }
+
+                let ty = &field.ty;
+                let parse = match attr {
+                    Some(FieldAttr::With(path)) => path.to_token_stream(),
+                    _ => quote!(<#ty as ::core::str::FromStr>::from_str),
+                };
+                let var = format_ident!("_{i}");
+                quote!(let #var = ::pattern_parse::derive::placeholder(input, &mut s, #until, #parse)?;)
+                    .to_tokens(&mut steps);
+                inits.push(quote!(#member: #var));
+                parsed.push(member);
+            }
+        }
+    }
+
+    for (member, field, attr) in &fields {
+        if parsed.contains(member) {
+            continue;
+        }
+        if !matches!(attr, Some(FieldAttr::Default)) {
+            let msg = "field is missing from the pattern, mark it with #[pattern(default)] to use its default value";
+            return Err(syn::Error::new_spanned(field, msg));
+        }
+        inits.push(quote!(#member: ::core::default::Default::default()));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote!(
+        impl #impl_generics ::pattern_parse::PatternParse for #name #ty_generics #where_clause {
+            type Error = ::pattern_parse::ParseError;
+
+            fn parse(input: &str) -> Result<(Self, usize), Self::Error> {
+                let mut s = input;
+                #steps
+                Ok((Self { #( #inits ),* }, input.len() - s.len()))
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::pattern_parse::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                ::pattern_parse::derive::complete(s)
+            }
+        }
+    ))
+}
+
+/// Derives [`FromStr`](std::str::FromStr) and `PatternParse` for a struct from a `#[pattern("...")]` attribute
+///
+/// - Placeholders name the field they are parsed into, like `{name}`, or `{0}` for tuple structs
+/// - `{_}` skips the text up to the next literal
+/// - A placeholder is parsed from the text up to the next occurrence of the following literal,
+///   or up to the end of the input if it is the last part of the pattern
+/// - Fields are parsed with [`FromStr`](std::str::FromStr) unless marked with `#[pattern(with = path)]`
+/// - Fields missing from the pattern have to be marked with `#[pattern(default)]`
+///
+/// `Input` is not derived, as `pattern_parse` does not depend on `common`.
+/// The derived `FromStr` lets the type be read with the line adapters instead,
+/// like `Linewise<T>` for one value per line or `Multiline<T, N, PADDED>` for values spanning lines.
+///
+/// See the `pattern_parse` crate for an example.
+#[proc_macro_derive(PatternParse, attributes(pattern))]
+pub fn derive_pattern_parse(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse_macro_input!(stream as syn::DeriveInput);
+    derive_core(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +406,19 @@ mod tests {
         assert_eq!(pattern, expected);
     }
 
+    #[test]
+    fn segments_placeholders() {
+        let segments = segments("Valve {name} has {_}").unwrap();
+        let expected = vec![
+            Segment::Literal("Valve "),
+            Segment::Placeholder("name"),
+            Segment::Literal(" has "),
+            Segment::Placeholder("_"),
+        ];
+        assert_eq!(segments, expected);
+        assert!(super::segments("x={i32").is_err());
+    }
+
     fn lit(s: &'static str) -> PatternElement {
         PatternElement::Literal(s)
     }
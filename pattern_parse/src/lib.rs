@@ -1,10 +1,35 @@
-//! A crate to provide a macro to simplify parsing certain values from a larger string
+//! A crate to provide macros to simplify parsing certain values from a larger string
 //!
-//! There currently only exists the [`parse_fn`] macro.
+//! [`parse_fn`] generates a function returning a tuple of the parsed values,
+//! the [`PatternParse`](macro@PatternParse) derive parses a struct with its fields named in the pattern:
+//!
+//! ```
+//! use pattern_parse::PatternParse;
+//!
+//! #[derive(PatternParse)]
+//! #[pattern("Valve {name} has flow rate={flow_rate}; {_} to valves {tunnels}")]
+//! struct Valve {
+//!     name: String,
+//!     flow_rate: u32,
+//!     #[pattern(with = parse_tunnels)]
+//!     tunnels: Vec<String>,
+//! }
+//!
+//! fn parse_tunnels(s: &str) -> Result<Vec<String>, std::convert::Infallible> {
+//!     Ok(s.split(", ").map(String::from).collect())
+//! }
+//!
+//! let valve: Valve = "Valve AA has flow rate=0; tunnels lead to valves DD, II".parse().unwrap();
+//! assert_eq!(valve.name, "AA");
+//! assert_eq!(valve.tunnels, ["DD", "II"]);
+//! ```
+
+// lets the derive refer to `::pattern_parse` inside this crate as well
+extern crate self as pattern_parse;
 
 use std::{fmt::Display, num::ParseIntError, str::FromStr, borrow::Cow, convert::Infallible};
 
-pub use pattern_parse_macros::parse_fn;
+pub use pattern_parse_macros::{parse_fn, PatternParse};
 
 /// Core trait for parsable items
 pub trait PatternParse: Sized {
@@ -118,3 +143,110 @@ impl Display for LiteralMismatch {
 }
 
 impl std::error::Error for LiteralMismatch {}
+
+/// The input continues after the whole pattern was matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailingInput(pub String);
+
+impl Display for TrailingInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unexpected input after the pattern: \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for TrailingInput {}
+
+/// Helpers for the code generated by the [`PatternParse`](macro@PatternParse) derive.
+#[doc(hidden)]
+pub mod derive {
+    use super::*;
+
+    fn error(input: &str, s: &str, error: impl 'static + std::error::Error) -> ParseError {
+        ParseError { error: Box::new(error), position: input.len() - s.len() }
+    }
+
+    fn mismatch(input: &str, s: &str, expected: &'static str) -> ParseError {
+        error(input, s, LiteralMismatch { expected: expected.into(), got: s.into() })
+    }
+
+    pub fn literal(input: &str, s: &mut &str, lit: &'static str) -> Result<(), ParseError> {
+        *s = s.strip_prefix(lit).ok_or_else(|| mismatch(input, s, lit))?;
+        Ok(())
+    }
+
+    /// Splits off the text up to `until`, or the rest of the input for [`None`].
+    fn take<'a>(input: &str, s: &mut &'a str, until: Option<&'static str>) -> Result<&'a str, ParseError> {
+        let len = match until {
+            Some(lit) => s.find(lit).ok_or_else(|| mismatch(input, s, lit))?,
+            None => s.len(),
+        };
+        let (value, rest) = s.split_at(len);
+        *s = rest;
+        Ok(value)
+    }
+
+    pub fn skip(input: &str, s: &mut &str, until: Option<&'static str>) -> Result<(), ParseError> {
+        take(input, s, until).map(|_| ())
+    }
+
+    pub fn placeholder<T, E>(
+        input: &str,
+        s: &mut &str,
+        until: Option<&'static str>,
+        parse: impl FnOnce(&str) -> Result<T, E>,
+    ) -> Result<T, ParseError>
+    where
+        E: 'static + std::error::Error,
+    {
+        let start = *s;
+        let value = take(input, s, until)?;
+        parse(value).map_err(|e| error(input, start, e))
+    }
+
+    pub fn complete<T: PatternParse<Error = ParseError>>(input: &str) -> Result<T, ParseError> {
+        let (value, len) = T::parse(input)?;
+        match &input[len..] {
+            "" => Ok(value),
+            rest => Err(error(input, rest, TrailingInput(rest.into()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, PatternParse)]
+    #[pattern("move {count} from {from} to {to}")]
+    struct Move {
+        from: usize,
+        to: usize,
+        count: u32,
+        #[pattern(default)]
+        done: bool,
+    }
+
+    #[derive(Debug, PartialEq, PatternParse)]
+    #[pattern("<{0}>")]
+    struct Tag(String);
+
+    #[test]
+    fn derive_fields() {
+        let parsed = "move 3 from 1 to 2".parse::<Move>().unwrap();
+        assert_eq!(parsed, Move { from: 1, to: 2, count: 3, done: false });
+        assert_eq!(Tag::parse("<a>b").unwrap(), (Tag("a".into()), 3));
+    }
+
+    #[test]
+    fn derive_errors() {
+        let err = "move 3 from x to 2".parse::<Move>().unwrap_err();
+        assert_eq!(err.position, 12);
+        assert!(err.error.is::<ParseIntError>());
+
+        let err = "move 3 to 2".parse::<Move>().unwrap_err();
+        assert!(err.error.is::<LiteralMismatch>());
+
+        let err = "<a>b".parse::<Tag>().unwrap_err();
+        assert_eq!((err.position, err.to_string()), (3, "Parsing error at position 3: Unexpected input after the pattern: \"b\"".into()));
+    }
+}
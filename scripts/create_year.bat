@@ -12,6 +12,15 @@ cd "%1"
 cargo add --path "../pattern_parse"
 cargo add --path "../common"
 
+:: register the days found in src automatically
+cargo add --build --path "../common"
+(
+echo fn main^(^) -^> std::io::Result^<^(^)^> {
+echo     common::discover::year^(^)
+echo }
+) > build.rs
+echo include!(concat!(env!("OUT_DIR"), "/days.rs"));> src\lib.rs
+
 :: used for nice error handling
 cargo add "thiserror@1.0.37" 
 :: generally nice
@@ -0,0 +1,47 @@
+//! Formatting of task results as printed by the runners.
+
+use std::time::Duration;
+
+use common::{Day, Task, Year};
+
+pub fn format_simple(res: Result<String, String>) -> String {
+    let (status, message) = match res {
+        Ok(ok) => ("OK ", ok),
+        Err(e) => ("ERR", e),
+    };
+
+    format!("{} {}", status, message)
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let s = duration.as_secs();
+    let ms = duration.subsec_millis();
+    let ys = duration.subsec_micros() % 1000;
+    let ns = duration.subsec_nanos() % 1000;
+    if duration.as_secs() > 0 {
+        format!("{s}.{ms}s")
+    } else if ms > 0 {
+        format!("{ms}.{ys}ms")
+    } else if ys > 0 {
+        format!("{ys}.{ns}ys")
+    } else {
+        format!("{ns}ns")
+    }
+}
+
+/// Formats the result of a task with the time spent solving it.
+/// `parse` is the time spent in the shared parse step of the day, which is left blank if there is none.
+pub fn format_detailed(res: Result<String, String>, y: &Year, d: &Day, t: &Task, duration: Duration, parse: Option<Duration>) -> String {
+    let (status, message) = match res {
+        Ok(ok) => ("OK ", ok),
+        Err(e) => ("ERR", e),
+    };
+
+    let duration = format_duration(duration);
+    let parse = parse.map(format_duration).unwrap_or_default();
+    let year = y.name;
+    let day = d.name;
+    let task = t.name;
+
+    format!("{status} [{duration:9}] [{parse:9}] {year:8}::{day:0>5}::{task:5} {message}")
+}
@@ -0,0 +1,37 @@
+use std::fs::File;
+use std::ops::Deref;
+use std::path::Path;
+
+/// The complete content of an input file, read once and handed to the tasks as a whole.
+pub enum Contents {
+    Owned(Vec<u8>),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Deref for Contents {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Contents::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            Contents::Mapped(map) => map,
+        }
+    }
+}
+
+/// Loads the file at `path`, memory mapping it if the `mmap` feature is enabled.
+pub fn load(path: &Path) -> Result<Contents, std::io::Error> {
+    let mut file = File::open(path)?;
+
+    #[cfg(feature = "mmap")]
+    if file.metadata()?.len() > 0 {
+        // SAFETY: the inputs are not expected to be modified while a task is running
+        return unsafe { memmap2::Mmap::map(&file) }.map(Contents::Mapped);
+    }
+
+    let mut bytes = Vec::new();
+    std::io::Read::read_to_end(&mut file, &mut bytes)?;
+    Ok(Contents::Owned(bytes))
+}
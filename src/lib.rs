@@ -0,0 +1,53 @@
+//! The registry of all solutions together with the logic to run them,
+//! shared by the command line and terminal interfaces and usable by other programs.
+//!
+//! ```
+//! use advent_of_code::{input, registry, solve};
+//!
+//! let (year, day, task) = registry::task("aoc_2024", "day14", "task1")?;
+//! let input = input::load(&registry::example_path(year, day))?;
+//! let (result, elapsed, parse) = solve::execute(day, task, &input, &task.example_params());
+//! assert_eq!(result.as_deref(), Ok("12"));
+//! println!("{}", advent_of_code::format::format_detailed(result, year, day, task, elapsed, parse));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub mod format;
+pub mod input;
+#[cfg(feature = "plugins")]
+pub mod plugin;
+pub mod registry;
+pub mod solve;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use common;
+
+#[cfg(test)]
+mod tests {
+    use std::process::Command;
+
+    /// Checks that the library builds for WebAssembly without the native only features.
+    #[test]
+    #[ignore = "needs the wasm32-unknown-unknown target, run with `cargo test -- --ignored`"]
+    fn builds_for_wasm() {
+        const TARGET: &str = "wasm32-unknown-unknown";
+        let sysroot = Command::new("rustc").args(["--print", "sysroot"]).output().unwrap().stdout;
+        let sysroot = String::from_utf8(sysroot).unwrap();
+        assert!(
+            std::path::Path::new(sysroot.trim()).join("lib/rustlib").join(TARGET).is_dir(),
+            "the target {TARGET} is not installed, add it with `rustup target add {TARGET}`"
+        );
+
+        let manifest = env!("CARGO_MANIFEST_DIR");
+        let output = Command::new(env!("CARGO"))
+            .args(["build", "--lib", "--target", TARGET, "--no-default-features", "--features", "wasm"])
+            .arg("--manifest-path")
+            .arg(format!("{manifest}/Cargo.toml"))
+            // a separate target directory avoids waiting on the lock held by the running `cargo test`
+            .env("CARGO_TARGET_DIR", format!("{manifest}/target/wasm"))
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    }
+}
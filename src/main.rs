@@ -1,31 +1,33 @@
-pub use aoc_2021;
-pub use aoc_2022;
-pub use aoc_2023;
-
-use std::time::Duration;
-use common::*;
-
 mod runner {
     #[cfg(feature = "parallel")]
     pub mod all;
+    #[cfg(any(feature = "parallel", feature = "interactive"))]
+    pub mod batch;
     pub mod cli;
+    pub mod compare;
+    pub mod list;
     pub mod run;
     #[cfg(feature = "interactive")]
     pub mod tui;
 }
 
-static YEARS: &[&common::Year] = &[
-    &aoc_2021::YEAR,
-    &aoc_2022::YEAR,
-    &aoc_2023::YEAR,
-    &aoc_2024::YEAR,
-];
-
 #[derive(Debug, clap_derive::Parser)]
-pub enum Args {
+pub struct Args {
+    #[clap(long = "plugin", global = true, help = "Loads additional years from a plugin library. (i.e. ./libmy_solutions.so)")]
+    plugins: Vec<std::path::PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, clap_derive::Subcommand)]
+pub enum Command {
     #[cfg(feature = "parallel")]
     #[command(about = "Runs all tasks in parallel printing all the results.")]
     All(runner::all::Args),
+    #[command(about = "Runs all implementations of the specified task checking that their answers agree.")]
+    Compare(runner::compare::Args),
+    #[command(about = "Lists all years, days and tasks.")]
+    List(runner::list::Args),
     #[command(about = "Runs the specified task and returns.")]
     Run(runner::run::Args),
     #[cfg(feature = "interactive")]
@@ -34,54 +36,24 @@ pub enum Args {
 }
 
 fn main() -> Result<(), anyhow::Error> {
-    if std::env::args().count() > 1 {
-        let args = <Args as clap::Parser>::parse();
-        match args {
-            Args::All(args) => runner::all::run(args)?,
-            Args::Run(args) => runner::run::run(args)?,
-            Args::Tui(args) => runner::tui::run(args)?,
-        };
-    } else {
-        runner::cli::run()?;
-    }
-    Ok(())
-}
+    let args = <Args as clap::Parser>::parse();
 
-fn format_simple(res: Result<String, String>) -> String {
-    let (status, message) = match res {
-        Ok(ok) => ("OK ", ok),
-        Err(e) => ("ERR", e),
-    };
-
-    format!("{} {}", status, message)
-}
-
-fn format_duration(duration: Duration) -> String {
-    let s = duration.as_secs();
-    let ms = duration.subsec_millis();
-    let ys = duration.subsec_micros() % 1000;
-    let ns = duration.subsec_nanos() % 1000;
-    if duration.as_secs() > 0 {
-        format!("{s}.{ms}s")
-    } else if ms > 0 {
-        format!("{ms}.{ys}ms")
-    } else if ys > 0 {
-        format!("{ys}.{ns}ys")
-    } else {
-        format!("{ns}ns")
+    #[cfg(feature = "plugins")]
+    advent_of_code::registry::load_plugins(&args.plugins)?;
+    #[cfg(not(feature = "plugins"))]
+    if !args.plugins.is_empty() {
+        anyhow::bail!("Plugins are not supported without the 'plugins' feature");
     }
-}
 
-fn format_detailed(res: Result<String, String>, y: &Year, d: &Day, t: &Task, duration: Duration) -> String {
-    let (status, message) = match res {
-        Ok(ok) => ("OK ", ok),
-        Err(e) => ("ERR", e),
+    match args.command {
+        #[cfg(feature = "parallel")]
+        Some(Command::All(args)) => runner::all::run(args)?,
+        Some(Command::Compare(args)) => runner::compare::run(args)?,
+        Some(Command::List(args)) => runner::list::run(args)?,
+        Some(Command::Run(args)) => runner::run::run(args)?,
+        #[cfg(feature = "interactive")]
+        Some(Command::Tui(args)) => runner::tui::run(args)?,
+        None => runner::cli::run()?,
     };
-
-    let duration = format_duration(duration);
-    let year = y.name;
-    let day = d.name;
-    let task = t.name;
-
-    format!("{status} [{duration:9}] {year:8}::{day:0>5}::{task:5} {message}")
-}
\ No newline at end of file
+    Ok(())
+}
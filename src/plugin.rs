@@ -0,0 +1,85 @@
+//! Loading of years compiled into separate libraries, see [`common::plugin`].
+
+use std::path::{Path, PathBuf};
+
+use common::plugin::{Registration, ABI_VERSION, SYMBOL};
+use common::Year;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to load plugin {0:?}: {1}")]
+    Load(PathBuf, #[source] libloading::Error),
+    #[error("Plugin {path:?} was built with '{found}' but the runner uses '{ABI_VERSION}'")]
+    Abi { path: PathBuf, found: String },
+    #[error("Day {year}::{day} is declared more than once")]
+    Duplicate { year: &'static str, day: &'static str },
+    #[error("Plugins have to be loaded before the registry is used")]
+    TooLate,
+}
+
+/// Loads the library at `path` and returns the years it registers.
+/// The library stays loaded for the rest of the program as the years refer into it.
+pub fn load(path: &Path) -> Result<&'static [&'static Year], Error> {
+    // SAFETY: loading runs the initializers of the library, which we trust just like our own code
+    let library = unsafe { libloading::Library::new(path) }.map_err(|e| Error::Load(path.to_owned(), e))?;
+    // SAFETY: `export_plugin!` declares the symbol as a `Registration`, which consists of raw pointers only
+    let registration = unsafe { library.get::<*const Registration>(SYMBOL) }
+        .map(|symbol| *symbol)
+        .map_err(|e| Error::Load(path.to_owned(), e))?;
+    // SAFETY: the symbol points to a static which lives as long as the library
+    let registration = unsafe { &*registration };
+
+    let years = registration.years().ok_or_else(|| Error::Abi {
+        path: path.to_owned(),
+        found: registration.abi_version(),
+    })?;
+    std::mem::forget(library);
+    Ok(years)
+}
+
+/// Adds the years of a plugin to `years`.
+/// Days of a year which already exists are appended to it, but every day may only be declared once.
+pub fn merge(years: &mut Vec<&'static Year>, plugin: &'static [&'static Year]) -> Result<(), Error> {
+    for &year in plugin {
+        let Some(existing) = years.iter_mut().find(|y| y.name == year.name) else {
+            years.push(year);
+            continue;
+        };
+        if let Some(day) = year.days.iter().find(|d| existing.day(d.name).is_some()) {
+            return Err(Error::Duplicate { year: year.name, day: day.name });
+        }
+        let mut days = existing.days.to_vec();
+        days.extend(year.days.iter().cloned());
+        days.sort_by_key(|d| d.name);
+        *existing = Box::leak(Box::new(Year { name: year.name, days: days.leak() }));
+    }
+    years.sort_by_key(|y| y.name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Day, Meta};
+
+    fn year(name: &'static str, days: &[&'static str]) -> &'static Year {
+        let days = days.iter().map(|&name| Day { name, meta: Meta::default(), parse: None, tasks: &[] });
+        Box::leak(Box::new(Year { name, days: days.collect::<Vec<_>>().leak() }))
+    }
+
+    fn names<T>(items: &[T], name: impl Fn(&T) -> &'static str) -> Vec<&'static str> {
+        items.iter().map(name).collect()
+    }
+
+    #[test]
+    fn merges_years() {
+        let mut years = vec![year("aoc_2024", &["day01"])];
+        let plugin = vec![year("aoc_2015", &["day01"]), year("aoc_2024", &["day02"])].leak();
+        merge(&mut years, plugin).unwrap();
+        assert_eq!(names(&years, |y| y.name), ["aoc_2015", "aoc_2024"]);
+        assert_eq!(names(years[1].days, |d| d.name), ["day01", "day02"]);
+
+        let duplicate = vec![year("aoc_2024", &["day01"])].leak();
+        assert!(matches!(merge(&mut years, duplicate), Err(Error::Duplicate { day: "day01", .. })));
+    }
+}
@@ -0,0 +1,96 @@
+//! Lookup of the years, days and tasks compiled into the runner.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use common::{Day, Task, Year};
+
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotFound {
+    #[error("Failed to find year {0}")]
+    Year(String),
+    #[error("Failed to find day {0}")]
+    Day(String),
+    #[error("Failed to find task {0}")]
+    Task(String),
+}
+
+static REGISTRY: OnceLock<Vec<&'static Year>> = OnceLock::new();
+
+/// All years in ascending order, including those of loaded plugins.
+pub fn years() -> &'static [&'static Year] {
+    REGISTRY.get_or_init(|| YEARS.to_vec())
+}
+
+/// Adds the years of the plugins at `paths` to the built in ones.
+/// Has to be called before the registry is used for the first time.
+#[cfg(feature = "plugins")]
+pub fn load_plugins<P: AsRef<std::path::Path>>(paths: &[P]) -> Result<(), crate::plugin::Error> {
+    let mut years = YEARS.to_vec();
+    for path in paths {
+        crate::plugin::merge(&mut years, crate::plugin::load(path.as_ref())?)?;
+    }
+    REGISTRY.set(years).map_err(|_| crate::plugin::Error::TooLate)
+}
+
+pub fn year(name: &str) -> Result<&'static Year, NotFound> {
+    years()
+        .iter()
+        .copied()
+        .find(|y| y.name == name)
+        .ok_or_else(|| NotFound::Year(name.to_owned()))
+}
+
+pub fn day(year: &str, day: &str) -> Result<(&'static Year, &'static Day), NotFound> {
+    let year = self::year(year)?;
+    let day = year.day(day).ok_or_else(|| NotFound::Day(day.to_owned()))?;
+    Ok((year, day))
+}
+
+/// Looks up a task by the names of its year, day and itself, i.e. `("aoc_2024", "day14", "task1")`.
+pub fn task(year: &str, day: &str, task: &str) -> Result<(&'static Year, &'static Day, &'static Task), NotFound> {
+    let (year, day) = self::day(year, day)?;
+    let task = day.task(task).ok_or_else(|| NotFound::Task(task.to_owned()))?;
+    Ok((year, day, task))
+}
+
+/// Iterates over every task of every year.
+pub fn tasks() -> impl Iterator<Item = (&'static Year, &'static Day, &'static Task)> {
+    years()
+        .iter()
+        .flat_map(|y| y.days.iter().flat_map(move |d| d.tasks.iter().map(move |t| (*y, d, t))))
+}
+
+/// The default location of the input of a day, `./YEAR/inputs/DAY.txt`.
+pub fn input_path(year: &Year, day: &Day) -> PathBuf {
+    let mut path = PathBuf::from_iter([year.name, "inputs", day.name]);
+    path.set_extension("txt");
+    path
+}
+
+/// The default location of the example input of a day, `./YEAR/inputs/DAY.example.txt`.
+pub fn example_path(year: &Year, day: &Day) -> PathBuf {
+    let mut path = PathBuf::from_iter([year.name, "inputs", day.name]);
+    path.set_extension("example.txt");
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let (year, day, task) = task("aoc_2024", "day14", "task1").unwrap();
+        assert_eq!((year.name, day.name, task.name), ("aoc_2024", "day14", "task1"));
+        assert_eq!(input_path(year, day), PathBuf::from("aoc_2024/inputs/day14.txt"));
+        assert_eq!(example_path(year, day), PathBuf::from("aoc_2024/inputs/day14.example.txt"));
+
+        assert!(matches!(self::task("aoc_1999", "day01", "task1"), Err(NotFound::Year(_))));
+        assert!(matches!(self::task("aoc_2024", "day26", "task1"), Err(NotFound::Day(_))));
+        assert!(matches!(self::task("aoc_2024", "day14", "task3"), Err(NotFound::Task(_))));
+        assert!(tasks().count() > years().len());
+    }
+}
@@ -1,53 +1,32 @@
 use std::convert::Infallible;
-use std::io::{Write, BufReader};
-use std::path::PathBuf;
-use std::time::Duration;
-use rayon::prelude::*;
+use std::io::Write;
+use std::sync::atomic::AtomicBool;
+
+use advent_of_code::format::format_detailed;
+
+use super::batch::{self, Event, Filter};
 
 #[derive(Debug, clap_derive::Parser)]
-pub struct Args;
+pub struct Args {
+    #[clap(flatten)]
+    filter: Filter,
+}
 
-pub fn run(_: Args) -> Result<(), Infallible> {
-    let tasks = crate::YEARS
-        .iter()
-        .flat_map(move |y| {
-            y.days.iter().flat_map(move |d| {
-                let mut path = PathBuf::from_iter([y.name, "inputs", d.name]);
-                path.set_extension("txt");
-                d.tasks.iter().map(move |t| (y, d, t, path.clone()))
-            })
-        })
+pub fn run(args: Args) -> Result<(), Infallible> {
+    let jobs = batch::all_jobs()
+        .into_iter()
+        .filter(|j| args.filter.matches(j))
         .collect::<Vec<_>>();
     let stdout = std::io::stdout();
-    let total_time = tasks
-        .into_par_iter()
-        .map(move |t| {
-            let (year, day, task, path) = t;
-
-            let result;
-            let elapsed;
-            match std::fs::File::open(&path) {
-                Ok(file) => {
-                    let mut buf = BufReader::new(file);
-
-                    let time = std::time::Instant::now();
-                    result = task.run(&mut buf);
-                    elapsed = time.elapsed();
-                }
-                Err(err) => {
-                    result = Err(format!("{err}"));
-                    elapsed = Duration::ZERO;
-                }
-            };
-
+    let total_time = batch::run(&jobs, &AtomicBool::new(false), |event| {
+        if let Event::Finished { index, result, elapsed, parse } = event {
+            let job = &jobs[index];
             let _ = stdout.lock().write_fmt(
                 format_args!("{}\r\n",
-                             crate::format_detailed(result, year, day, task, elapsed))
+                             format_detailed(result, job.year, job.day, job.task, elapsed, parse))
             );
-
-            elapsed
-        })
-        .sum::<Duration>();
+        }
+    });
 
     println!("Finished!\ntotal time: {:?}", total_time);
     Ok(())
@@ -0,0 +1,135 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+#[cfg(feature = "parallel")]
+use common::Tag;
+use common::{Day, Task, Year};
+
+use advent_of_code::{registry, solve};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A single task scheduled for execution together with the input it should be run on.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub year: &'static Year,
+    pub day: &'static Day,
+    pub task: &'static Task,
+    pub path: PathBuf,
+}
+
+impl Job {
+    /// Creates a job reading from the default input location `./YEAR/inputs/DAY.txt`.
+    pub fn new(year: &'static Year, day: &'static Day, task: &'static Task) -> Self {
+        let path = registry::input_path(year, day);
+        Self { year, day, task, path }
+    }
+
+    /// Whether both jobs run tasks of the same day on the same input, so they can share the parse step.
+    fn shares_input(&self, other: &Job) -> bool {
+        std::ptr::eq(self.day, other.day) && self.path == other.path
+    }
+}
+
+/// Selects jobs by the metadata declared for their day and task.
+#[cfg(feature = "parallel")]
+#[derive(Debug, Default, Clone, clap_derive::Args)]
+pub struct Filter {
+    #[clap(long = "tag", help = "Only runs tasks with any of the given tags. (i.e. grid, pathfinding, simulation, parsing, number-theory)")]
+    pub tags: Vec<Tag>,
+    #[clap(long, help = "Skips tasks which are marked as slow.")]
+    pub skip_slow: bool,
+}
+
+#[cfg(feature = "parallel")]
+impl Filter {
+    pub fn matches(&self, job: &Job) -> bool {
+        if self.skip_slow && job.day.is_slow(job.task) {
+            return false;
+        }
+        self.tags.is_empty() || job.day.tags_of(job.task).any(|t| self.tags.contains(&t))
+    }
+}
+
+/// Progress notifications emitted while a batch is running.
+#[derive(Debug)]
+pub enum Event {
+    Started(usize),
+    Finished {
+        index: usize,
+        result: Result<String, String>,
+        elapsed: Duration,
+        /// The time spent in the shared parse step, if the day has one.
+        parse: Option<Duration>,
+    },
+}
+
+/// Collects a job for every task of every year.
+pub fn all_jobs() -> Vec<Job> {
+    registry::tasks().map(|(y, d, t)| Job::new(y, d, t)).collect()
+}
+
+/// Executes all `jobs` (in parallel if the `parallel` feature is enabled) reporting progress through `on_event`.
+/// The input of jobs sharing a day and input file is loaded and parsed only once.
+/// Jobs which have not started yet are skipped once `cancel` is set, running jobs are not interrupted.
+/// Returns the accumulated time spent inside the tasks and parse steps.
+pub fn run<F>(jobs: &[Job], cancel: &AtomicBool, on_event: F) -> Duration
+where
+    F: Fn(Event) + Sync,
+{
+    let mut groups = Vec::<Vec<usize>>::new();
+    for (index, job) in jobs.iter().enumerate() {
+        match groups.iter_mut().find(|g| jobs[g[0]].shares_input(job)) {
+            Some(group) => group.push(index),
+            None => groups.push(vec![index]),
+        }
+    }
+
+    let execute = |group: &Vec<usize>| {
+        if cancel.load(Ordering::Relaxed) {
+            return Duration::ZERO;
+        }
+        let first = &jobs[group[0]];
+        let input = match advent_of_code::input::load(&first.path) {
+            Ok(input) => input,
+            Err(err) => {
+                for &index in group {
+                    on_event(Event::Started(index));
+                    on_event(Event::Finished { index, result: Err(format!("{err}")), elapsed: Duration::ZERO, parse: None });
+                }
+                return Duration::ZERO;
+            }
+        };
+        let parsed = match group.iter().any(|&i| jobs[i].task.solve.is_some()) {
+            true => solve::parse(first.day, &input),
+            false => None,
+        };
+        let parse = parsed.as_ref().map(|p| p.1);
+
+        let solve = |&index: &usize| {
+            if cancel.load(Ordering::Relaxed) {
+                return Duration::ZERO;
+            }
+            on_event(Event::Started(index));
+            let task = jobs[index].task;
+            let (result, elapsed) = solve::solve(task, &input, parsed.as_ref().map(|p| &p.0), &task.defaults());
+            on_event(Event::Finished { index, result, elapsed, parse });
+            elapsed
+        };
+
+        #[cfg(feature = "parallel")]
+        let total: Duration = group.par_iter().map(solve).sum();
+        #[cfg(not(feature = "parallel"))]
+        let total: Duration = group.iter().map(solve).sum();
+
+        total + parse.unwrap_or_default()
+    };
+
+    #[cfg(feature = "parallel")]
+    let total = groups.par_iter().map(execute).sum();
+    #[cfg(not(feature = "parallel"))]
+    let total = groups.iter().map(execute).sum();
+
+    total
+}
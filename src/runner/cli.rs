@@ -1,4 +1,7 @@
-use std::{fmt::Display, io::BufReader};
+use std::fmt::Display;
+
+use advent_of_code::format::format_simple;
+use advent_of_code::registry;
 
 fn get_input<T, E>(prompt: &str) -> T
 where
@@ -29,7 +32,7 @@ where
     S: Ord,
 {
     let mut days = elements.into_iter().collect::<Vec<_>>();
-    days.sort_by(|a, b| sort(a).cmp(&sort(b)));
+    days.sort_by_key(|a| sort(a));
     days.iter()
         .enumerate()
         .for_each(|(i, t)| println!("({:2}) {}", i, t));
@@ -44,7 +47,7 @@ where
 }
 
 pub fn run() -> Result<(), std::io::Error> {
-    let year = select_from_list(crate::YEARS, |y| y.name);
+    let year = select_from_list(registry::years(), |y| y.name);
     let day = select_from_list(year.days, |d| d.name);
     let task = select_from_list(day.tasks, |t| t.name);
 
@@ -58,11 +61,10 @@ pub fn run() -> Result<(), std::io::Error> {
         };
     };
 
-    let file = std::fs::File::open(&full).unwrap();
-    let mut buf = BufReader::new(file);
-    let result = task.run(&mut buf);
+    let input = advent_of_code::input::load(&full)?;
+    let result = task.run(&input, &task.defaults());
 
-    println!("{}", crate::format_simple(result));
+    println!("{}", format_simple(result));
 
     println!("Press enter to exit...");
     std::io::stdin().read_line(&mut String::new())?;
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use advent_of_code::format::format_duration;
+use advent_of_code::solve;
+
+use super::run::{self, Resolved, Target};
+
+#[derive(Debug, clap_derive::Parser)]
+pub struct Args {
+    #[clap(flatten)]
+    target: Target,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Run(#[from] run::Error),
+    #[error("The implementations of {0} disagree")]
+    Mismatch(String),
+}
+
+pub fn run(args: Args) -> Result<(), Error> {
+    let Resolved { year, day, task, params, input } = args.target.resolve()?;
+    let (parse, runs) = solve::compare(day, task, &input, &params);
+
+    let fastest = runs.iter().map(|r| r.elapsed).min().unwrap_or_default().max(Duration::from_nanos(1));
+    let width = runs.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    if let Some(parse) = parse {
+        println!("parse step: {}", format_duration(parse));
+    }
+    for run in &runs {
+        let (status, message) = match &run.result {
+            Ok(ok) => ("OK ", ok),
+            Err(e) => ("ERR", e),
+        };
+        let relative = run.elapsed.as_secs_f64() / fastest.as_secs_f64();
+        let duration = format_duration(run.elapsed);
+        println!("{status} [{duration:9}] [{relative:>7.2}x] {:width$} {message}", run.name);
+    }
+
+    let path = format!("{}::{}::{}", year.name, day.name, task.name);
+    let agree = runs.iter().all(|r| r.result.is_ok() && r.result == runs[0].result);
+    if !agree {
+        return Err(Error::Mismatch(path));
+    }
+    match runs.len() {
+        1 => println!("{path} has no alternative implementations"),
+        n => println!("All {n} implementations of {path} agree"),
+    }
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use std::io::Write;
+
+use advent_of_code::registry;
+
+#[derive(Debug, clap_derive::Parser)]
+pub struct Args {
+    #[clap(help = "Only lists the days of the given year. (i.e. aoc_2024)")]
+    year: Option<String>,
+}
+
+pub fn run(args: Args) -> Result<(), std::io::Error> {
+    let mut out = std::io::stdout().lock();
+    let years = registry::years().iter().filter(|y| args.year.as_ref().is_none_or(|name| y.name == name));
+    for year in years {
+        writeln!(out, "{}", year.name)?;
+        for day in year.days {
+            let title = day.meta.title.map(|t| format!(" {t}")).unwrap_or_default();
+            writeln!(out, "  {}{}", day.name, title)?;
+            for task in day.tasks {
+                let params = task.defaults();
+                write!(out, "    {}", task.name)?;
+                if !params.is_empty() {
+                    write!(out, " ({params})")?;
+                }
+                for alternative in task.alternatives {
+                    write!(out, " | {}", alternative.name)?;
+                }
+                writeln!(out)?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,17 @@
-use std::{path::PathBuf, io::BufReader};
+use std::path::PathBuf;
 use std::fs::File;
 use std::io::stdout;
 
-#[derive(Debug, clap_derive::Parser)]
-pub struct Args {
+use advent_of_code::format::format_detailed;
+use advent_of_code::registry::{self, NotFound};
+use advent_of_code::input::{self, Contents};
+use advent_of_code::solve;
+use common::params::{Override, ParamError, Params};
+use common::{Day, Task, Year};
+
+/// Selects a task together with its input and parameters.
+#[derive(Debug, clap_derive::Args)]
+pub struct Target {
     #[clap(help = "The year of the task to be run. (i.e. inputs)")]
     year: String,
     #[clap(help = "The day of the task to be run. (i.e. day01)")]
@@ -12,70 +20,68 @@ pub struct Args {
     task: String,
     #[clap(short, long, help = "The path to the input file. If omitted it will be assumed to './YEAR/inputs/DAY.txt'.")]
     input: Option<PathBuf>,
-    #[clap(short, long, help = "The path to the output file. If omitted the result will be written to stdout.")]
-    output: Option<PathBuf>,
+    #[clap(short, long = "param", help = "Overrides a parameter of the task. (i.e. width=11)")]
+    params: Vec<Override>,
+    #[clap(short, long, help = "Uses the example parameters of the task. The input defaults to './YEAR/inputs/DAY.example.txt'.")]
+    example: bool,
 }
 
-#[derive(Debug, thiserror::Error)]
-pub enum PartNotFound {
-    #[error("Failed to find year {0}")]
-    Year(String),
-    #[error("Failed to find day {0}")]
-    Day(String),
-    #[error("Failed to find task {0}")]
-    Task(String),
+#[derive(Debug, clap_derive::Parser)]
+pub struct Args {
+    #[clap(flatten)]
+    target: Target,
+    #[clap(short, long, help = "The path to the output file. If omitted the result will be written to stdout.")]
+    output: Option<PathBuf>,
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error(transparent)]
-    PartNotFound(#[from] PartNotFound),
+    PartNotFound(#[from] NotFound),
     #[error("Failed to find file '{0:?}'")]
     FileNotFound(PathBuf),
     #[error(transparent)]
-    IoError(#[from] std::io::Error),
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Param(#[from] ParamError),
 }
 
-pub fn run(args: Args) -> Result<(), Error> {
-    let y = crate::YEARS
-        .iter()
-        .filter(|y| y.name == args.year)
-        .next();
-
-    let year = match y {
-        Some(y) => y,
-        None => return Err(PartNotFound::Year(args.year.to_owned()).into()),
-    };
-
-    let d = year.days.into_iter().filter(|d| d.name == args.day).next();
+/// A task resolved from a [`Target`] with its loaded input.
+pub struct Resolved {
+    pub year: &'static Year,
+    pub day: &'static Day,
+    pub task: &'static Task,
+    pub params: Params,
+    pub input: Contents,
+}
 
-    let day = match d {
-        Some(d) => d,
-        None => return Err(PartNotFound::Day(args.day.to_owned()).into()),
-    };
+impl Target {
+    pub fn resolve(self) -> Result<Resolved, Error> {
+        let (year, day, task) = registry::task(&self.year, &self.day, &self.task)?;
 
-    let t = day.tasks.into_iter().filter(|d| d.name == args.task).next();
+        let params = match self.example {
+            true => task.example_params(),
+            false => task.defaults(),
+        };
+        let params = params.with_overrides(&self.params)?;
 
-    let task = match t {
-        Some(d) => d,
-        None => return Err(PartNotFound::Task(args.task.to_owned()).into()),
-    };
+        let input_path = match (self.input, self.example) {
+            (Some(p), _) => p,
+            (None, false) => registry::input_path(year, day),
+            (None, true) => registry::example_path(year, day),
+        };
 
-    let input_path = match args.input {
-        Some(p) => p,
-        None => PathBuf::from(format!(
-            "{}/inputs/{}.txt", args.year, args.day
-        ))
-    };
+        let input = input::load(&input_path)
+            .map_err(move |_| Error::FileNotFound(input_path))?;
 
-    let file = File::open(&input_path)
-        .map_err(move |_| Error::FileNotFound(input_path))?;
+        Ok(Resolved { year, day, task, params, input })
+    }
+}
 
-    let mut buf = BufReader::new(file);
+pub fn run(args: Args) -> Result<(), Error> {
+    let Resolved { year, day, task, params, input } = args.target.resolve()?;
 
-    let time = std::time::Instant::now();
-    let result = task.run(&mut buf);
-    let elapsed = time.elapsed();
+    let (result, elapsed, parse) = solve::execute(day, task, &input, &params);
 
     let mut file_out;
     let mut std_out;
@@ -86,7 +92,7 @@ pub fn run(args: Args) -> Result<(), Error> {
             output = &mut std_out;
         },
         Some(path) => match File::open(path.clone()) {
-            Err(e) => return Err(Error::IoError(e)),
+            Err(e) => return Err(Error::Io(e)),
             Ok(f) => {
                 file_out = f;
                 output = &mut file_out;
@@ -94,6 +100,6 @@ pub fn run(args: Args) -> Result<(), Error> {
         }
     };
 
-    write!(output, "{}", crate::format_detailed(result, year, day, task, elapsed))?;
+    write!(output, "{}", format_detailed(result, year, day, task, elapsed, parse))?;
     Ok(())
 }
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use tui::backend::Backend;
+use tui::layout::{Constraint, Direction, Layout};
+use tui::style::{Color, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, BorderType, Borders, Paragraph};
+use tui::Terminal;
+
+const MAX_ZOOM: usize = 4;
+
+/// Returns true if the answer spans multiple lines and should be shown as a picture rather than a list entry.
+pub fn is_picture(answer: &str) -> bool {
+    answer.trim().contains('\n')
+}
+
+/// Returns the single line summary of an answer used inside of lists.
+pub fn summary(answer: &str) -> String {
+    if is_picture(answer) {
+        let lines = pixel_rows(answer);
+        let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        match common::ocr::decode_str(answer) {
+            Ok(text) => format!("{text} [{width}x{} picture]", lines.len()),
+            Err(_) => format!("[{width}x{} picture]", lines.len()),
+        }
+    } else {
+        answer.trim().to_owned()
+    }
+}
+
+/// Splits a picture answer into its rows of lit/unlit pixels stripping blank leading and trailing rows.
+fn pixel_rows(answer: &str) -> Vec<Vec<bool>> {
+    let mut rows = answer
+        .lines()
+        .map(|l| l.trim_end_matches('\r').chars().map(is_lit).collect::<Vec<_>>())
+        .skip_while(|r| !r.contains(&true))
+        .collect::<Vec<_>>();
+    while rows.last().is_some_and(|r| !r.contains(&true)) {
+        rows.pop();
+    }
+    rows
+}
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+struct View {
+    zoom: usize,
+    scroll: (u16, u16),
+    show_decoded: bool,
+}
+
+impl View {
+    /// Renders the picture scaling every pixel by the current zoom factor.
+    /// Terminal cells are roughly twice as high as they are wide, so the vertical scale is halved.
+    fn picture_lines(&self, rows: &[Vec<bool>]) -> Vec<Spans<'static>> {
+        let horizontal = self.zoom;
+        let vertical = self.zoom.div_ceil(2);
+        let lit = Style::default().fg(Color::Yellow);
+
+        rows.iter()
+            .flat_map(|row| {
+                let line = row
+                    .iter()
+                    .map(|p| if *p { '█' } else { ' ' }.to_string().repeat(horizontal))
+                    .collect::<String>();
+                std::iter::repeat_n(Spans::from(Span::styled(line, lit)), vertical)
+            })
+            .collect()
+    }
+}
+
+/// Shows an answer in a scrollable view.
+/// Picture answers can be zoomed and are decoded as block letters using [`common::ocr`] if possible.
+pub fn show<B>(term: &mut Terminal<B>, title: &str, result: &Result<String, String>) -> Result<(), std::io::Error>
+where
+    B: Backend,
+{
+    let (status, answer) = match result {
+        Ok(ok) => ("OK", ok.as_str()),
+        Err(e) => ("ERR", e.as_str()),
+    };
+    let picture = is_picture(answer);
+    let rows = pixel_rows(answer);
+    let decoded = if picture { common::ocr::decode_str(answer).ok() } else { None };
+
+    let mut view = View {
+        zoom: 1,
+        scroll: (0, 0),
+        show_decoded: true,
+    };
+
+    loop {
+        term.draw(|f| {
+            let decoded_height = match (&decoded, view.show_decoded) {
+                (Some(_), true) => 3,
+                _ => 0,
+            };
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(decoded_height),
+                    Constraint::Min(0),
+                    Constraint::Length(1),
+                ])
+                .split(f.size());
+
+            if let (Some(text), true) = (&decoded, view.show_decoded) {
+                let block = Block::default()
+                    .title("Decoded")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded);
+                f.render_widget(Paragraph::new(text.as_str()).block(block), chunks[0]);
+            }
+
+            let lines = if picture {
+                view.picture_lines(&rows)
+            } else {
+                answer.lines().map(|l| Spans::from(l.to_owned())).collect()
+            };
+            let block = Block::default()
+                .title(format!("{status} {title} (zoom {}x)", view.zoom))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+            let paragraph = Paragraph::new(lines).block(block).scroll(view.scroll);
+            f.render_widget(paragraph, chunks[1]);
+
+            let help = " arrows: scroll | +/-: zoom | o: toggle decoded text | q/enter: close";
+            f.render_widget(Paragraph::new(help), chunks[2]);
+        })?;
+
+        if !crossterm::event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        let key = match crossterm::event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        let (y, x) = &mut view.scroll;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => break,
+            KeyCode::Up => *y = y.saturating_sub(1),
+            KeyCode::Down => *y = y.saturating_add(1),
+            KeyCode::Left => *x = x.saturating_sub(1),
+            KeyCode::Right => *x = x.saturating_add(1),
+            KeyCode::Char('+') => view.zoom = (view.zoom + 1).min(MAX_ZOOM),
+            KeyCode::Char('-') => view.zoom = (view.zoom - 1).max(1),
+            KeyCode::Char('o') => view.show_decoded = !view.show_decoded,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PICTURE: &str = "\r
+#..#.####.###..###.\r
+#..#.#....#..#.#..#\r
+####.###..#..#.#..#\r
+#..#.#....###..###.\r
+#..#.#....#....#.#.\r
+#..#.####.#....#..#\r
+";
+
+    #[test]
+    fn summary_picture() {
+        assert!(is_picture(PICTURE));
+        assert_eq!(pixel_rows(PICTURE).len(), 6);
+        assert_eq!(summary(PICTURE), "HEPR [19x6 picture]");
+    }
+
+    #[test]
+    fn summary_single_line() {
+        assert!(!is_picture("  42 \n"));
+        assert_eq!(summary("  42 \n"), "42");
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use tui::backend::Backend;
+use tui::layout::{Constraint, Direction, Layout};
+use tui::widgets::{Block, BorderType, Borders, Paragraph};
+use tui::Terminal;
+
+use super::{handle_list_events, Action, StatefulList};
+
+/// Maximum number of lines read from a file for its preview.
+const PREVIEW_LINES: usize = 256;
+
+struct Entry {
+    name: String,
+    is_dir: bool,
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_dir {
+            write!(f, "{}/", self.name)
+        } else {
+            f.write_str(&self.name)
+        }
+    }
+}
+
+/// Lists the directory with the parent entry first, followed by all directories and then all files.
+fn read_entries(dir: &Path) -> Vec<Entry> {
+    let mut entries = match std::fs::read_dir(dir) {
+        Ok(read) => read
+            .filter_map(Result::ok)
+            .map(|e| Entry {
+                name: e.file_name().to_string_lossy().into_owned(),
+                is_dir: e.path().is_dir(),
+            })
+            .collect::<Vec<_>>(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+    if dir.parent().is_some() {
+        entries.insert(0, Entry { name: "..".to_owned(), is_dir: true });
+    }
+    entries
+}
+
+fn breadcrumbs(dir: &Path) -> String {
+    let parts = dir
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    format!(" {} ", parts.join(" > "))
+}
+
+/// Returns the first lines of a file or the content of a directory.
+fn preview(path: &Path) -> String {
+    if path.is_dir() {
+        return read_entries(path)
+            .iter()
+            .filter(|e| e.name != "..")
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    match File::open(path) {
+        Ok(file) => BufReader::new(file)
+            .lines()
+            .take(PREVIEW_LINES)
+            .map(|l| l.unwrap_or_else(|_| "<binary>".to_owned()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Err(e) => format!("{e}"),
+    }
+}
+
+/// Lets the user browse the file system starting at `start` and pick an input file.
+/// If `preferred` names an entry of the starting directory it is highlighted initially.
+/// Returns [`None`] if the user backs out of the browser.
+pub fn select_file<B>(term: &mut Terminal<B>, start: PathBuf, preferred: Option<&str>) -> Result<Option<PathBuf>, std::io::Error>
+where
+    B: Backend,
+{
+    let mut dir = start.canonicalize()?;
+    let mut preferred = preferred.map(str::to_owned);
+
+    loop {
+        let entries = read_entries(&dir);
+        let initial = preferred
+            .take()
+            .and_then(|p| entries.iter().position(|e| e.name == p))
+            .unwrap_or(0);
+        let title = breadcrumbs(&dir);
+        let mut list = StatefulList::new(&title, entries, initial);
+        list.footer = " enter: open | backspace: parent directory | esc: back".to_owned();
+
+        // the preview is only read again when the selection changes, not on every redraw
+        let mut shown: Option<(PathBuf, String)> = None;
+        let next = loop {
+            let selected = list.state.selected().map(|i| dir.join(&list.items[i].name));
+            if shown.as_ref().map(|(path, _)| path) != selected.as_ref() {
+                shown = selected.map(|path| {
+                    let text = preview(&path);
+                    (path, text)
+                });
+            }
+            let text = shown.as_ref().map_or("", |(_, text)| text.as_str());
+
+            term.draw(|f| {
+                let chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+                    .split(f.size());
+                f.render_widget(&mut list, chunks[0]);
+
+                let block = Block::default()
+                    .title("Preview")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded);
+                f.render_widget(Paragraph::new(text).block(block), chunks[1]);
+            })?;
+
+            match handle_list_events(&mut list)? {
+                Some(Action::Select(i)) => {
+                    let entry = &list.items[i];
+                    let path = dir.join(&entry.name);
+                    if !entry.is_dir {
+                        return Ok(Some(path));
+                    }
+                    match path.canonicalize() {
+                        Ok(path) => break path,
+                        Err(_) => continue,
+                    }
+                }
+                Some(Action::Parent) => {
+                    if let Some(parent) = dir.parent() {
+                        preferred = dir.file_name().map(|n| n.to_string_lossy().into_owned());
+                        break parent.to_owned();
+                    }
+                }
+                Some(Action::Back) => return Ok(None),
+                _ => {}
+            }
+        };
+        dir = next;
+    }
+}
@@ -0,0 +1,717 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+use std::{fmt::Display, io::stderr, path::PathBuf};
+
+use advent_of_code::format::{format_detailed, format_duration, format_simple};
+use advent_of_code::registry;
+use common::params::Params;
+use common::viz::Recorder;
+use common::{Day, Meta, Task, Year};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crossterm::{
+    event::{Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen},
+};
+use tui::buffer::Buffer;
+use tui::layout::{Constraint, Direction, Layout, Rect};
+use tui::style::{Color, Style};
+use tui::{
+    backend::{Backend, CrosstermBackend},
+    widgets::{Block, BorderType, Gauge, List, ListItem, ListState, Paragraph},
+    Terminal,
+};
+
+use tui::widgets::{Borders, StatefulWidget, Widget};
+
+use super::batch::{self, Job};
+
+mod answer;
+mod files;
+mod params;
+mod playback;
+
+#[derive(Debug, clap_derive::Parser)]
+pub struct Args;
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+struct StatefulList<'a, T> {
+    title: &'a str,
+    state: ListState,
+    items: Vec<T>,
+    marks: Vec<Mark>,
+    footer: String,
+    /// Number of visible rows during the last render, used for paging.
+    page: usize,
+}
+
+impl<'a, T> StatefulList<'a, T> {
+    fn new(title: &'a str, items: Vec<T>, selected: usize) -> Self {
+        let mut state = ListState::default();
+        if !items.is_empty() {
+            state.select(Some(selected.min(items.len() - 1)));
+        }
+        Self {
+            title,
+            state,
+            items,
+            marks: Vec::new(),
+            footer: String::new(),
+            page: 1,
+        }
+    }
+}
+
+impl<T: Display> Widget for &mut StatefulList<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let items = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, t)| match self.marks.get(i) {
+                Some(m) => ListItem::new(format!("{} {}", m.symbol(), t)),
+                None => ListItem::new(format!("{}", t)),
+            })
+            .collect::<Vec<_>>();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(self.title)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            )
+            .highlight_symbol(" >> ");
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(area);
+        self.page = chunks[0].height.saturating_sub(2).max(1) as usize;
+        StatefulWidget::render(list, chunks[0], buf, &mut self.state);
+        Paragraph::new(self.footer.as_str()).render(chunks[1], buf);
+    }
+}
+
+/// Moves the highlighted entry of a list with `len` entries according to `key`.
+/// Up and Down wrap around, paging and jumping to either end stops at the boundaries.
+/// Returns false if the key is not a navigation key.
+fn navigate(state: &mut ListState, len: usize, page: usize, key: KeyCode) -> bool {
+    if len == 0 {
+        return matches!(key, KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End);
+    }
+    let last = len - 1;
+    let current = state.selected();
+    let i = match key {
+        KeyCode::Up => match current {
+            Some(0) | None => last,
+            Some(i) => i - 1,
+        },
+        KeyCode::Down => match current {
+            Some(i) if i < last => i + 1,
+            _ => 0,
+        },
+        KeyCode::PageUp => current.unwrap_or(0).saturating_sub(page),
+        KeyCode::PageDown => (current.unwrap_or(0) + page).min(last),
+        KeyCode::Home => 0,
+        KeyCode::End => last,
+        _ => return false,
+    };
+    state.select(Some(i));
+    true
+}
+
+/// How many of the tasks belonging to a list entry are marked for batch execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mark {
+    None,
+    Partial,
+    All,
+}
+
+impl Mark {
+    fn symbol(self) -> &'static str {
+        match self {
+            Mark::None => "[ ]",
+            Mark::Partial => "[~]",
+            Mark::All => "[x]",
+        }
+    }
+}
+
+/// List entries which can be marked for batch execution as a whole.
+trait Markable {
+    fn tasks(&self) -> Vec<&'static Task>;
+}
+
+impl Markable for &'static Year {
+    fn tasks(&self) -> Vec<&'static Task> {
+        self.days.iter().flat_map(|d| d.tasks.iter()).collect()
+    }
+}
+
+impl Markable for &'static Day {
+    fn tasks(&self) -> Vec<&'static Task> {
+        self.tasks.iter().collect()
+    }
+}
+
+impl Markable for &'static Task {
+    fn tasks(&self) -> Vec<&'static Task> {
+        vec![*self]
+    }
+}
+
+/// A day or task listed together with its declared metadata.
+struct Described<T>(T, Meta);
+
+impl<T: Display> Display for Described<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:6}", self.0.to_string())?;
+        if let Some(title) = self.1.title {
+            write!(f, " {title}")?;
+        }
+        if !self.1.tags.is_empty() {
+            let tags = self.1.tags.iter().map(|t| t.name()).collect::<Vec<_>>();
+            write!(f, " ({})", tags.join(", "))?;
+        }
+        if self.1.slow {
+            f.write_str(" [slow]")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Markable> Markable for Described<T> {
+    fn tasks(&self) -> Vec<&'static Task> {
+        self.0.tasks()
+    }
+}
+
+/// The set of tasks marked for batch execution.
+#[derive(Default)]
+struct Marked(Vec<&'static Task>);
+
+impl Marked {
+    fn contains(&self, task: &Task) -> bool {
+        self.0.iter().any(|t| std::ptr::eq(*t, task))
+    }
+
+    fn mark_of(&self, tasks: &[&'static Task]) -> Mark {
+        let count = tasks.iter().filter(|t| self.contains(t)).count();
+        match count {
+            0 => Mark::None,
+            c if c == tasks.len() => Mark::All,
+            _ => Mark::Partial,
+        }
+    }
+
+    /// Unmarks all `tasks` if they are all marked already, otherwise marks the missing ones.
+    fn toggle(&mut self, tasks: &[&'static Task]) {
+        if self.mark_of(tasks) == Mark::All {
+            self.0.retain(|t| !tasks.iter().any(|o| std::ptr::eq(*o, *t)));
+        } else {
+            for &t in tasks {
+                if !self.contains(t) {
+                    self.0.push(t);
+                }
+            }
+        }
+    }
+
+    /// Creates the jobs for all marked tasks in declaration order.
+    fn jobs(&self) -> Vec<Job> {
+        batch::all_jobs()
+            .into_iter()
+            .filter(|j| self.contains(j.task))
+            .collect()
+    }
+}
+
+enum Action {
+    Select(usize),
+    Toggle(usize),
+    ToggleYear,
+    RunBatch,
+    Search,
+    Visualize(usize),
+    Back,
+    Parent,
+}
+
+enum Selection {
+    Item(usize),
+    Visualize(usize),
+    Found(Job),
+    Batch,
+    Back,
+}
+
+fn handle_list_events<T>(list: &mut StatefulList<T>) -> Result<Option<Action>, std::io::Error> {
+    let key = match crossterm::event::read()? {
+        Event::Key(key) => key,
+        _ => return Ok(None),
+    };
+    if key.kind != KeyEventKind::Press {
+        return Ok(None);
+    }
+    if navigate(&mut list.state, list.items.len(), list.page, key.code) {
+        return Ok(None);
+    }
+    let action = match key.code {
+        KeyCode::Enter => list.state.selected().map(Action::Select),
+        KeyCode::Char(' ') => list.state.selected().map(Action::Toggle),
+        KeyCode::Char('a') => Some(Action::ToggleYear),
+        KeyCode::Char('r') => Some(Action::RunBatch),
+        KeyCode::Char('/') => Some(Action::Search),
+        KeyCode::Char('v') => list.state.selected().map(Action::Visualize),
+        KeyCode::Esc | KeyCode::Char('q') => Some(Action::Back),
+        KeyCode::Backspace | KeyCode::Left => Some(Action::Parent),
+        _ => None,
+    };
+    Ok(action)
+}
+
+/// Lets the user pick an entry of `iter`, search for a task or request execution of the marked batch.
+/// `year` is the year whose tasks are toggled by `a`, if omitted the highlighted entry is toggled instead.
+fn select<B, T, I>(
+    title: &str,
+    term: &mut Terminal<B>,
+    iter: I,
+    selected: usize,
+    marked: &mut Marked,
+    year: Option<&'static Year>,
+) -> Result<Selection, std::io::Error>
+where
+    B: Backend,
+    T: Display + Markable,
+    I: IntoIterator<Item = T>,
+{
+    let items = iter.into_iter().collect::<Vec<_>>();
+    let tasks = items.iter().map(Markable::tasks).collect::<Vec<_>>();
+    let mut list = StatefulList::new(title, items, selected);
+
+    let value = loop {
+        list.marks = tasks.iter().map(|t| marked.mark_of(t)).collect();
+        list.footer = format!(
+            " space: toggle | a: toggle year | r: run marked ({}) | /: search | v: visualize | enter: select | esc: back",
+            marked.0.len()
+        );
+        term.draw(|f| {
+            let area = f.size();
+            f.render_widget(&mut list, area);
+        })?;
+
+        match handle_list_events(&mut list)? {
+            Some(Action::Select(i)) => break Selection::Item(i),
+            Some(Action::Toggle(i)) => marked.toggle(&tasks[i]),
+            Some(Action::ToggleYear) => match year {
+                Some(y) => marked.toggle(&y.tasks()),
+                None => if let Some(i) = list.state.selected() {
+                    marked.toggle(&tasks[i]);
+                },
+            },
+            Some(Action::RunBatch) if !marked.0.is_empty() => break Selection::Batch,
+            Some(Action::Search) => if let Some(job) = search(term, marked)? {
+                break Selection::Found(job);
+            },
+            Some(Action::Visualize(i)) => break Selection::Visualize(i),
+            Some(Action::Back | Action::Parent) => break Selection::Back,
+            _ => {}
+        }
+    };
+    Ok(value)
+}
+
+/// Returns true if `label` contains every whitespace separated term of `query` ignoring case.
+fn matches_query(label: &str, query: &str) -> bool {
+    let label = label.to_lowercase();
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .all(|term| label.contains(term))
+}
+
+/// Incrementally filters all tasks of all years by the typed query.
+/// Returns the chosen task or [`None`] if the search was cancelled.
+fn search<B>(term: &mut Terminal<B>, marked: &mut Marked) -> Result<Option<Job>, std::io::Error>
+where
+    B: Backend,
+{
+    let all = batch::all_jobs();
+    let mut query = String::new();
+    let mut state = ListState::default();
+    let mut page = 1;
+
+    loop {
+        let found = all
+            .iter()
+            .filter(|j| matches_query(&format!("{}::{}::{}", j.year.name, j.day.name, j.task.name), &query))
+            .collect::<Vec<_>>();
+        match state.selected() {
+            _ if found.is_empty() => state.select(None),
+            Some(i) if i >= found.len() => state.select(Some(found.len() - 1)),
+            None => state.select(Some(0)),
+            _ => {}
+        }
+
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)])
+                .split(f.size());
+
+            let input = Paragraph::new(format!("{query}_")).block(
+                Block::default()
+                    .title("Search")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            );
+            f.render_widget(input, chunks[0]);
+
+            let items = found
+                .iter()
+                .map(|j| {
+                    let mark = marked.mark_of(&[j.task]);
+                    ListItem::new(format!("{} {}::{}::{}", mark.symbol(), j.year.name, j.day.name, j.task.name))
+                })
+                .collect::<Vec<_>>();
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title(format!("{} matches", found.len()))
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .highlight_symbol(" >> ");
+            page = chunks[1].height.saturating_sub(2).max(1) as usize;
+            f.render_stateful_widget(list, chunks[1], &mut state);
+
+            let help = " type to filter | tab: toggle | enter: select | esc: cancel";
+            f.render_widget(Paragraph::new(help), chunks[2]);
+        })?;
+
+        let key = match crossterm::event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        if navigate(&mut state, found.len(), page, key.code) {
+            continue;
+        }
+        match key.code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Enter => if let Some(i) = state.selected() {
+                return Ok(Some(found[i].clone()));
+            },
+            KeyCode::Tab => if let Some(i) = state.selected() {
+                marked.toggle(&[found[i].task]);
+            },
+            KeyCode::Backspace => {
+                query.pop();
+            }
+            KeyCode::Char(c) => {
+                query.push(c);
+                state.select(None);
+            }
+            _ => {}
+        }
+    }
+}
+
+enum RowState {
+    Pending,
+    Running(Instant),
+    /// The result along with the time spent solving and parsing.
+    Done(Result<String, String>, Duration, Option<Duration>),
+}
+
+struct BatchRow {
+    job: Job,
+    state: RowState,
+}
+
+impl BatchRow {
+    fn label(&self, tick: usize) -> String {
+        let Job { year, day, task, .. } = self.job;
+        match &self.state {
+            RowState::Pending => format!("... [{:9}] [{:9}] {:8}::{:0>5}::{:5}", "", "", year.name, day.name, task.name),
+            RowState::Running(start) => {
+                let spinner = SPINNER[tick % SPINNER.len()];
+                let elapsed = format_duration(start.elapsed());
+                format!(" {spinner}  [{elapsed:9}] [{:9}] {:8}::{:0>5}::{:5}", "", year.name, day.name, task.name)
+            }
+            RowState::Done(result, elapsed, parse) => {
+                let result = result.as_ref().map(|a| answer::summary(a)).map_err(Clone::clone);
+                format_detailed(result, year, day, task, *elapsed, *parse)
+            }
+        }
+    }
+
+    fn title(&self) -> String {
+        let Job { year, day, task, .. } = self.job;
+        format!("{}::{}::{}", year.name, day.name, task.name)
+    }
+}
+
+/// Runs the jobs on a background thread while rendering their progress.
+/// Returns the rows in their final state once the user leaves the view.
+fn run_batch<B>(term: &mut Terminal<B>, jobs: Vec<Job>) -> Result<Vec<BatchRow>, std::io::Error>
+where
+    B: Backend,
+{
+    let mut rows = jobs
+        .iter()
+        .cloned()
+        .map(|job| BatchRow { job, state: RowState::Pending })
+        .collect::<Vec<_>>();
+
+    let (sender, receiver) = mpsc::channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let worker_cancel = Arc::clone(&cancel);
+    std::thread::spawn(move || {
+        batch::run(&jobs, &worker_cancel, |event| {
+            let _ = sender.send(event);
+        });
+    });
+
+    let start = Instant::now();
+    let mut finished = 0;
+    let mut end = None;
+    let mut tick = 0_usize;
+    let mut list_state = ListState::default();
+    let mut page = 1;
+
+    loop {
+        for event in receiver.try_iter() {
+            match event {
+                batch::Event::Started(i) => rows[i].state = RowState::Running(Instant::now()),
+                batch::Event::Finished { index, result, elapsed, parse } => {
+                    rows[index].state = RowState::Done(result, elapsed, parse);
+                    finished += 1;
+                }
+            }
+        }
+        if finished == rows.len() && end.is_none() {
+            end = Some(start.elapsed());
+        }
+
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)])
+                .split(f.size());
+
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .title("Progress")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .gauge_style(Style::default().fg(Color::Green))
+                .label(format!("{finished}/{}", rows.len()))
+                .ratio(finished as f64 / rows.len().max(1) as f64);
+            f.render_widget(gauge, chunks[0]);
+
+            let items = rows.iter().map(|r| ListItem::new(r.label(tick))).collect::<Vec<_>>();
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title("Tasks")
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .highlight_symbol(" >> ");
+            page = chunks[1].height.saturating_sub(2).max(1) as usize;
+            f.render_stateful_widget(list, chunks[1], &mut list_state);
+
+            let elapsed = format_duration(end.unwrap_or_else(|| start.elapsed()));
+            let status = match end {
+                Some(_) => format!(" finished in {elapsed} | enter: view answer | q: exit"),
+                None => format!(" elapsed {elapsed} | enter: view answer | q: abort"),
+            };
+            f.render_widget(Paragraph::new(status), chunks[2]);
+        })?;
+        tick += 1;
+
+        if !crossterm::event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        let key = match crossterm::event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        if navigate(&mut list_state, rows.len(), page, key.code) {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Enter => {
+                let row = list_state.selected().map(|i| &rows[i]);
+                if let Some(row @ BatchRow { state: RowState::Done(result, ..), .. }) = row {
+                    answer::show(term, &row.title(), result)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    cancel.store(true, Ordering::Relaxed);
+    Ok(rows)
+}
+
+enum Picked {
+    Single(Job, Params),
+    Visualize(Job, Params),
+    Batch,
+    Quit,
+}
+
+/// The steps of picking a single task, each remembering the choices made so far.
+enum Stage {
+    Year,
+    Day(&'static Year),
+    Task(&'static Year, &'static Day),
+    /// Selecting the input, the flag tells whether the task should be visualized.
+    File(&'static Year, &'static Day, &'static Task, bool),
+}
+
+fn pick<B: Backend>(term: &mut Terminal<B>, marked: &mut Marked) -> Result<Picked, std::io::Error> {
+    let mut stage = Stage::Year;
+    // the highlighted entries of the year, day and task lists when returning to them
+    let mut cursor = [0_usize; 3];
+
+    loop {
+        let selection = match stage {
+            Stage::Year => select("Select year", term, registry::years().iter().copied(), cursor[0], marked, None)?,
+            Stage::Day(year) => {
+                let days = year.days.iter().map(|d| Described(d, d.meta));
+                select("Select day", term, days, cursor[1], marked, Some(year))?
+            }
+            Stage::Task(year, day) => {
+                let tasks = day.tasks.iter().map(|t| Described(t, t.meta));
+                select("Select task", term, tasks, cursor[2], marked, Some(year))?
+            }
+            Stage::File(year, day, task, visualize) => {
+                let mut start = PathBuf::from_iter([year.name, "inputs"]);
+                if !start.is_dir() {
+                    start = std::env::current_dir()?;
+                }
+                let preferred = format!("{}.txt", day.name);
+                let Some(path) = files::select_file(term, start, Some(&preferred))? else {
+                    stage = Stage::Task(year, day);
+                    continue;
+                };
+                let is_example = path.file_name().is_some_and(|n| n.to_string_lossy().contains("example"));
+                let params = if is_example { task.example_params() } else { task.defaults() };
+                let params = match params.is_empty() {
+                    true => params,
+                    false => match params::edit(term, &format!("{}::{}::{}", year.name, day.name, task.name), params)? {
+                        Some(params) => params,
+                        None => continue,
+                    },
+                };
+                let job = Job { year, day, task, path };
+                return Ok(if visualize { Picked::Visualize(job, params) } else { Picked::Single(job, params) });
+            }
+        };
+
+        stage = match (selection, stage) {
+            (Selection::Batch, _) => return Ok(Picked::Batch),
+            (Selection::Found(job), _) => Stage::File(job.year, job.day, job.task, false),
+            (Selection::Back, Stage::Year) => return Ok(Picked::Quit),
+            (Selection::Back, Stage::Day(_)) => Stage::Year,
+            (Selection::Back, Stage::Task(year, _)) => Stage::Day(year),
+            (Selection::Item(i), Stage::Year) => {
+                cursor = [i, 0, 0];
+                Stage::Day(registry::years()[i])
+            }
+            (Selection::Item(i), Stage::Day(year)) => {
+                cursor[1..].copy_from_slice(&[i, 0]);
+                Stage::Task(year, &year.days[i])
+            }
+            (Selection::Item(i), Stage::Task(year, day)) => {
+                cursor[2] = i;
+                Stage::File(year, day, &day.tasks[i], false)
+            }
+            (Selection::Visualize(i), Stage::Task(year, day)) => {
+                cursor[2] = i;
+                Stage::File(year, day, &day.tasks[i], true)
+            }
+            (Selection::Visualize(_), stage) => stage,
+            (_, stage @ Stage::File(..)) => stage,
+        };
+    }
+}
+
+pub fn run(_: Args) -> Result<(), std::io::Error> {
+    enable_raw_mode()?;
+    execute!(stderr(), EnterAlternateScreen)?;
+
+    let backend = CrosstermBackend::new(stderr());
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut marked = Marked::default();
+    let picked = pick(&mut terminal, &mut marked)?;
+
+    match picked {
+        Picked::Quit => {
+            execute!(stderr(), LeaveAlternateScreen)?;
+            disable_raw_mode()?;
+            return Ok(());
+        }
+        Picked::Single(job, params) => {
+            let result = match advent_of_code::input::load(&job.path) {
+                Ok(input) => job.task.run(&input, &params),
+                Err(e) => Err(format!("{e}")),
+            };
+
+            let title = format!("{}::{}::{}", job.year.name, job.day.name, job.task.name);
+            answer::show(&mut terminal, &title, &result)?;
+
+            execute!(stderr(), LeaveAlternateScreen)?;
+            disable_raw_mode()?;
+
+            println!("{}", format_simple(result));
+        }
+        Picked::Visualize(job, params) => {
+            let mut recorder = Recorder::default();
+            let result = match advent_of_code::input::load(&job.path) {
+                Ok(input) => job
+                    .task
+                    .visualize(&input, &params, &mut recorder)
+                    .unwrap_or_else(|| Err(format!("{} has no visualization", job.task.name))),
+                Err(e) => Err(format!("{e}")),
+            };
+
+            let title = format!("{}::{}::{}", job.year.name, job.day.name, job.task.name);
+            let stride = recorder.stride();
+            playback::show(&mut terminal, &title, &recorder.into_frames(), stride)?;
+            answer::show(&mut terminal, &title, &result)?;
+
+            execute!(stderr(), LeaveAlternateScreen)?;
+            disable_raw_mode()?;
+
+            println!("{}", format_simple(result));
+        }
+        Picked::Batch => {
+            let rows = run_batch(&mut terminal, marked.jobs())?;
+
+            execute!(stderr(), LeaveAlternateScreen)?;
+            disable_raw_mode()?;
+
+            for row in rows {
+                if let RowState::Done(result, elapsed, parse) = row.state {
+                    let Job { year, day, task, .. } = row.job;
+                    println!("{}", format_detailed(result, year, day, task, elapsed, parse));
+                }
+            }
+        }
+    }
+
+    println!("Press enter to exit...");
+    std::io::stdin().read_line(&mut String::new())?;
+
+    Ok(())
+}
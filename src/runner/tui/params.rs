@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use common::params::Params;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use tui::backend::Backend;
+use tui::layout::{Constraint, Direction, Layout};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, BorderType, Borders, Paragraph};
+use tui::Terminal;
+
+/// Lets the user edit the values of `params` before running a task.
+/// Returns [`None`] if editing was cancelled.
+pub fn edit<B>(term: &mut Terminal<B>, title: &str, mut params: Params) -> Result<Option<Params>, std::io::Error>
+where
+    B: Backend,
+{
+    let names = params.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    let mut values = params.iter().map(|(_, value)| value.to_owned()).collect::<Vec<_>>();
+    let mut selected = 0;
+
+    loop {
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(f.size());
+
+            let width = names.iter().map(|n| n.len()).max().unwrap_or(0);
+            let lines = names
+                .iter()
+                .zip(&values)
+                .enumerate()
+                .map(|(i, (name, value))| {
+                    let style = match i == selected {
+                        true => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                        false => Style::default(),
+                    };
+                    let cursor = if i == selected { "_" } else { "" };
+                    Spans::from(Span::styled(format!("{name:>width$} = {value}{cursor}"), style))
+                })
+                .collect::<Vec<_>>();
+            let block = Block::default()
+                .title(format!("Parameters of {title}"))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+            f.render_widget(Paragraph::new(lines).block(block), chunks[0]);
+
+            let help = " up/down: select | type to edit | enter: run | esc: back";
+            f.render_widget(Paragraph::new(help), chunks[1]);
+        })?;
+
+        if !crossterm::event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        let key = match crossterm::event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        match key.code {
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Up => selected = selected.saturating_sub(1),
+            KeyCode::Down => selected = (selected + 1).min(names.len().saturating_sub(1)),
+            KeyCode::Backspace => {
+                values[selected].pop();
+            }
+            KeyCode::Char(c) => values[selected].push(c),
+            KeyCode::Enter => {
+                // the names were taken from the params themselves so setting them cannot fail
+                names.iter().zip(&values).try_for_each(|(name, value)| params.set(name, value)).ok();
+                return Ok(Some(params));
+            }
+            _ => {}
+        }
+    }
+}
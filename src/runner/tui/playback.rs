@@ -0,0 +1,151 @@
+use std::time::{Duration, Instant};
+
+use common::viz::Frame;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use tui::backend::Backend;
+use tui::layout::{Constraint, Direction, Layout};
+use tui::style::{Color, Style};
+use tui::text::Spans;
+use tui::widgets::{Block, BorderType, Borders, Gauge, Paragraph};
+use tui::Terminal;
+
+/// Selectable playback speeds in frames per second.
+const SPEEDS: [u32; 9] = [1, 2, 5, 10, 25, 50, 100, 250, 1000];
+
+struct Playback {
+    index: usize,
+    len: usize,
+    playing: bool,
+    speed: usize,
+    scroll: (u16, u16),
+}
+
+impl Playback {
+    fn frame_time(&self) -> Duration {
+        Duration::from_secs(1) / SPEEDS[self.speed]
+    }
+
+    fn seek(&mut self, index: usize) {
+        self.index = index.min(self.len.saturating_sub(1));
+    }
+
+    fn step(&mut self, forward: bool) {
+        self.playing = false;
+        match forward {
+            true => self.seek(self.index + 1),
+            false => self.seek(self.index.saturating_sub(1)),
+        }
+    }
+}
+
+/// Plays back the recorded frames of a visualized task.
+/// `stride` is the number of emitted frames every recorded frame stands for.
+pub fn show<B>(term: &mut Terminal<B>, title: &str, frames: &[Frame], stride: usize) -> Result<(), std::io::Error>
+where
+    B: Backend,
+{
+    let mut playback = Playback {
+        index: 0,
+        len: frames.len(),
+        playing: true,
+        speed: 3,
+        scroll: (0, 0),
+    };
+    let mut last_step = Instant::now();
+
+    loop {
+        term.draw(|f| {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(3),
+                    Constraint::Min(0),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ])
+                .split(f.size());
+
+            let state = if playback.playing { "playing" } else { "paused" };
+            let gauge = Gauge::default()
+                .block(
+                    Block::default()
+                        .title(format!("{title} ({state} at {} fps)", SPEEDS[playback.speed]))
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded),
+                )
+                .gauge_style(Style::default().fg(Color::Cyan))
+                .label(format!("frame {}/{}", playback.index + 1, playback.len))
+                .ratio((playback.index + 1) as f64 / playback.len.max(1) as f64);
+            f.render_widget(gauge, chunks[0]);
+
+            let (lines, status) = match frames.get(playback.index) {
+                Some(frame) => {
+                    let lines = frame
+                        .rows()
+                        .map(|r| Spans::from(r.iter().collect::<String>()))
+                        .collect::<Vec<_>>();
+                    (lines, frame.status.as_str())
+                }
+                None => (vec![Spans::from("The task did not emit any frames.")], ""),
+            };
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded);
+            f.render_widget(Paragraph::new(lines).block(block).scroll(playback.scroll), chunks[1]);
+
+            let status = match stride {
+                1 => format!(" {status}"),
+                s => format!(" {status} (1 of every {s} frames recorded)"),
+            };
+            f.render_widget(Paragraph::new(status), chunks[2]);
+
+            let help = " space: play/pause | left/right: step | +/-: speed | pgup/pgdn/0-9: seek | hjkl: pan | q: close";
+            f.render_widget(Paragraph::new(help), chunks[3]);
+        })?;
+
+        let timeout = match playback.playing {
+            true => playback.frame_time().saturating_sub(last_step.elapsed()),
+            false => Duration::from_millis(250),
+        };
+        if !crossterm::event::poll(timeout)? {
+            if playback.playing {
+                playback.seek(playback.index + 1);
+                playback.playing = playback.index + 1 < playback.len;
+                last_step = Instant::now();
+            }
+            continue;
+        }
+        let key = match crossterm::event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue,
+        };
+        let tenth = (playback.len / 10).max(1);
+        let (y, x) = &mut playback.scroll;
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter => break,
+            KeyCode::Char(' ') => {
+                if playback.index + 1 >= playback.len {
+                    playback.seek(0);
+                }
+                playback.playing = !playback.playing;
+                last_step = Instant::now();
+            }
+            KeyCode::Right => playback.step(true),
+            KeyCode::Left => playback.step(false),
+            KeyCode::Char('+') => playback.speed = (playback.speed + 1).min(SPEEDS.len() - 1),
+            KeyCode::Char('-') => playback.speed = playback.speed.saturating_sub(1),
+            KeyCode::PageDown => playback.seek(playback.index + tenth),
+            KeyCode::PageUp => playback.seek(playback.index.saturating_sub(tenth)),
+            KeyCode::Home => playback.seek(0),
+            KeyCode::End => playback.seek(playback.len),
+            KeyCode::Char(c @ '0'..='9') => playback.seek(playback.len * (c as usize - '0' as usize) / 10),
+            KeyCode::Char('h') => *x = x.saturating_sub(4),
+            KeyCode::Char('l') => *x = x.saturating_add(4),
+            KeyCode::Char('k') => *y = y.saturating_sub(2),
+            KeyCode::Char('j') => *y = y.saturating_add(2),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::time::{Duration, Instant};
+
+use common::params::Params;
+use common::{Day, Parsed, Task};
+
+/// Runs the shared parse step of `day` returning its output and the time spent on it.
+/// Returns [`None`] if the tasks of the day parse the input themselves.
+pub fn parse(day: &Day, input: &[u8]) -> Option<(Result<Parsed, String>, Duration)> {
+    let time = Instant::now();
+    let parsed = day.parse(input)?;
+    Some((parsed, time.elapsed()))
+}
+
+/// Runs `task` on the output of the shared parse step if there is one, otherwise on the raw input.
+/// Returns the result and the time spent inside the task.
+pub fn solve(
+    task: &Task,
+    input: &[u8],
+    parsed: Option<&Result<Parsed, String>>,
+    params: &Params,
+) -> (Result<String, String>, Duration) {
+    let time = Instant::now();
+    let result = match parsed {
+        Some(Ok(parsed)) => task.solve(parsed, params).unwrap_or_else(|| task.run(input, params)),
+        Some(Err(err)) => Err(err.clone()),
+        None => task.run(input, params),
+    };
+    (result, time.elapsed())
+}
+
+/// Parses and solves a single task.
+/// Returns the result, the time spent solving and the time spent in the shared parse step if there is one.
+pub fn execute(day: &Day, task: &Task, input: &[u8], params: &Params) -> (Result<String, String>, Duration, Option<Duration>) {
+    let parsed = match task.solve {
+        Some(_) => parse(day, input),
+        None => None,
+    };
+    let (result, elapsed) = solve(task, input, parsed.as_ref().map(|p| &p.0), params);
+    (result, elapsed, parsed.map(|p| p.1))
+}
+
+/// The outcome of a single implementation in [`compare`].
+#[derive(Debug)]
+pub struct Run {
+    pub name: &'static str,
+    pub result: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/// Runs `task` and all of its alternative implementations on the same input, parsing it only once.
+/// Returns the time spent in the shared parse step if there is one
+/// and the name, result and solving time of every implementation starting with the task itself.
+pub fn compare(
+    day: &Day,
+    task: &Task,
+    input: &[u8],
+    params: &Params,
+) -> (Option<Duration>, Vec<Run>) {
+    let parsed = match task.solve {
+        Some(_) => parse(day, input),
+        None => None,
+    };
+    let parsed_result = parsed.as_ref().map(|p| &p.0);
+
+    let (result, elapsed) = solve(task, input, parsed_result, params);
+    let mut runs = vec![Run { name: task.name, result, elapsed }];
+    for alternative in task.alternatives {
+        let time = Instant::now();
+        let result = match parsed_result {
+            Some(Ok(parsed)) => alternative.solve(parsed, params).unwrap_or_else(|| alternative.run(input, params)),
+            Some(Err(err)) => Err(err.clone()),
+            None => alternative.run(input, params),
+        };
+        runs.push(Run { name: alternative.name, result, elapsed: time.elapsed() });
+    }
+    (parsed.map(|p| p.1), runs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn alternatives_agree() {
+        let (_, day, task) = registry::task("aoc_2024", "day07", "task2").unwrap();
+        let input = b"190: 10 19\n3267: 81 40 27\n83: 17 5\n156: 15 6\n7290: 6 8 6 15\n161011: 16 10 13\n192: 17 8 14\n21037: 9 7 18 13\n292: 11 6 16 20\n";
+        let (parse, runs) = compare(day, task, input, &task.defaults());
+        assert_eq!(parse, None);
+        assert_eq!(runs.iter().map(|r| r.name).collect::<Vec<_>>(), ["task2", "task2_backwards"]);
+        assert!(runs.iter().all(|r| r.result == Ok("11387".to_owned())));
+    }
+}
@@ -0,0 +1,30 @@
+//! A string in, string out entry point for environments without a file system, such as a browser playground.
+//!
+//! The library builds for `wasm32-unknown-unknown` with the default features disabled:
+//!
+//! ```text
+//! cargo build --lib --target wasm32-unknown-unknown --no-default-features --features wasm
+//! ```
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::registry;
+
+/// Runs a task on `input` using the default parameters and returns its answer.
+#[wasm_bindgen]
+pub fn solve(year: &str, day: &str, task: &str, input: &str) -> Result<String, String> {
+    let (_, _, task) = registry::task(year, day, task).map_err(|e| e.to_string())?;
+    task.run(input.as_bytes(), &task.defaults())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_from_str() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+        assert_eq!(solve("aoc_2023", "day01", "task1", input), Ok("142".to_owned()));
+        assert_eq!(solve("aoc_2023", "day26", "task1", input), Err("Failed to find day day26".to_owned()));
+    }
+}